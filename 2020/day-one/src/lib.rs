@@ -0,0 +1,34 @@
+use std::io::{BufRead, BufReader, Error, ErrorKind, Read};
+
+use aoc::answer::{Accumulate, Answer, Overflow};
+use aoc_macros::{aoc, aoc_generator};
+use math::{combinations, CheckedAccumulate};
+
+pub fn read<R: Read>(io: R) -> Result<Vec<u64>, Error> {
+    let br = BufReader::new(io);
+    br.lines()
+        .map(|line| line.and_then(|v| v.parse().map_err(|e| Error::new(ErrorKind::InvalidData, e))))
+        .collect()
+}
+
+/// The product of the first `count` entries that add up to 2020, each entry only used once.
+pub fn find_it(nums: &[u64], count: usize) -> Option<Result<Answer, Overflow>> {
+    combinations(nums, count)
+        .find(|entries| entries.iter().map(|&&entry| entry).checked_sum() == Some(2020))
+        .map(|entries| entries.into_iter().copied().product_answers())
+}
+
+#[aoc_generator(2020, 1)]
+pub fn entries(input: &str) -> Vec<u64> {
+    read(input.as_bytes()).expect("the input is a number per line")
+}
+
+#[aoc(2020, 1, 1, input = "input")]
+pub fn part_one(nums: &[u64]) -> Result<Answer, Overflow> {
+    find_it(nums, 2).expect("We didn't find the number")
+}
+
+#[aoc(2020, 1, 2, input = "input")]
+pub fn part_two(nums: &[u64]) -> Result<Answer, Overflow> {
+    find_it(nums, 3).expect("We didn't find the number")
+}
@@ -0,0 +1,58 @@
+use aoc::{
+    answer::Answer,
+    parse::{parse_lines, Mode, Parse, ParseLines},
+    solution::SolveError,
+};
+use aoc_macros::aoc;
+
+/// A line of the password database: the policy the password was set under, then the password.
+#[derive(Debug, PartialEq, Clone, Copy, Parse)]
+#[format("{low}-{high} {letter}: {password}")]
+pub struct Entry<'a> {
+    pub low: usize,
+    pub high: usize,
+    pub letter: char,
+    pub password: &'a str,
+}
+
+impl Entry<'_> {
+    /// The sled rental place's policy: the letter turns up `low` to `high` times.
+    pub fn valid_for_sled_rental(&self) -> bool {
+        let count = self.password.chars().filter(|&c| c == self.letter).count();
+        (self.low..=self.high).contains(&count)
+    }
+
+    /// The toboggan company's policy: the letter is at exactly one of positions `low` and `high`,
+    /// counting from 1.
+    pub fn valid_for_toboggan(&self) -> bool {
+        let at = |position: usize| {
+            position
+                .checked_sub(1)
+                .and_then(|index| self.password.chars().nth(index))
+                == Some(self.letter)
+        };
+        at(self.low) != at(self.high)
+    }
+}
+
+pub fn entries(input: &str) -> ParseLines<'_, Entry<'_>> {
+    parse_lines(input, Mode::Strict)
+}
+
+/// How many passwords are valid under `policy`.
+fn count_valid<'a>(input: &'a str, policy: fn(&Entry<'a>) -> bool) -> Result<Answer, SolveError> {
+    let mut entries = entries(input);
+    let valid = entries.by_ref().filter(|(_, entry)| policy(entry)).count();
+    entries.finish()?;
+    Ok(valid.into())
+}
+
+#[aoc(2020, 2, 1, input = "input")]
+pub fn part_one(input: &str) -> Result<Answer, SolveError> {
+    count_valid(input, Entry::valid_for_sled_rental)
+}
+
+#[aoc(2020, 2, 2, input = "input")]
+pub fn part_two(input: &str) -> Result<Answer, SolveError> {
+    count_valid(input, Entry::valid_for_toboggan)
+}
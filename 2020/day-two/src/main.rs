@@ -1,3 +1 @@
-fn main() {
-    println!("Hello, world!");
-}
+aoc::main!(day_two_2020);
@@ -0,0 +1,42 @@
+//! Counts heap allocations, for the tests that check a part streams through its input without
+//! making any.
+//!
+//! Using this crate makes its counting allocator the global one, so it's only a dev-dependency.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Counts heap allocations per thread, so tests running in parallel don't see each other's.
+struct CountingAllocator;
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Runs `f`, returning its output and how many allocations it made on this thread.
+///
+/// ```
+/// use alloc_counter::allocations_during;
+/// assert_eq!(allocations_during(|| 1 + 1), (2, 0));
+/// assert_eq!(allocations_during(|| vec![1, 2]).1, 1);
+/// ```
+pub fn allocations_during<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCATIONS.with(Cell::get);
+    let output = f();
+    let after = ALLOCATIONS.with(Cell::get);
+    (output, after - before)
+}
@@ -0,0 +1,107 @@
+use std::path::PathBuf;
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    Expr, Ident, Token,
+};
+
+/// `part_one: <expr>`
+struct PartFn {
+    part: Ident,
+    function: Expr,
+}
+
+impl Parse for PartFn {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let part: Ident = input.parse()?;
+        if part != "part_one" && part != "part_two" {
+            return Err(syn::Error::new(
+                part.span(),
+                "expected `part_one` or `part_two`",
+            ));
+        }
+        input.parse::<Token![:]>()?;
+        Ok(Self {
+            part,
+            function: input.parse()?,
+        })
+    }
+}
+
+pub fn example_tests(input: TokenStream) -> syn::Result<TokenStream> {
+    let part_fns =
+        syn::parse::Parser::parse2(Punctuated::<PartFn, Token![,]>::parse_terminated, input)?;
+
+    let dir = PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap()).join("examples");
+    let mut expected_files = match std::fs::read_dir(&dir) {
+        Ok(entries) => entries
+            .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+            .filter(|file| file.ends_with(".expected"))
+            .collect::<Vec<_>>(),
+        Err(_) => vec![],
+    };
+    expected_files.sort();
+
+    let mut tests = vec![];
+    for file in expected_files {
+        let stem = file.trim_end_matches(".expected");
+        let Some((name, part)) = stem.rsplit_once('.') else {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                format!("examples/{file} should be named <name>.<part_one|part_two>.expected"),
+            ));
+        };
+        let Some(part_fn) = part_fns.iter().find(|part_fn| part_fn.part == part) else {
+            let parts = part_fns
+                .iter()
+                .map(|part_fn| format!("`{}`", part_fn.part))
+                .collect::<Vec<_>>();
+            return Err(syn::Error::new(
+                Span::call_site(),
+                format!(
+                    "examples/{file} is for `{part}`, which isn't one of the parts listed: {}",
+                    parts.join(", ")
+                ),
+            ));
+        };
+
+        let input_path = dir.join(format!("{name}.txt"));
+        if !input_path.is_file() {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                format!("examples/{file} has no examples/{name}.txt to go with it"),
+            ));
+        }
+        let input_path = input_path.to_string_lossy().into_owned();
+        let expected_path = dir.join(&file).to_string_lossy().into_owned();
+
+        let test_name = format_ident!("example_{}_{}", identifier(name), part);
+        let function = &part_fn.function;
+        tests.push(quote! {
+            #[test]
+            fn #test_name() {
+                let input = aoc::input::Input::new(include_str!(#input_path));
+                let expected = include_str!(#expected_path);
+                let answer = (#function)(&input).expect("the example should have an answer");
+                assert_eq!(answer, expected.trim());
+            }
+        });
+    }
+
+    Ok(quote! {
+        #[cfg(test)]
+        mod examples {
+            #(#tests)*
+        }
+    })
+}
+
+/// File names can have things in them that identifiers can't.
+fn identifier(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
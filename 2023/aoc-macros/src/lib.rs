@@ -0,0 +1,120 @@
+use proc_macro::TokenStream;
+
+mod examples;
+mod parse;
+mod solution;
+
+/// Generates a test per answer file in the crate's `examples/` directory.
+///
+/// Each `examples/<name>.txt` is an example input, and `examples/<name>.part_one.expected` or
+/// `examples/<name>.part_two.expected` holds the answer that part should give for it. Given how to
+/// call each part, this expands to a `#[cfg(test)] mod examples` with an `example_<name>_<part>`
+/// test per answer file, so adding a case is just dropping in the files. The parts return a
+/// `Result` of something that displays as the answer, like `aoc::answer::Answer`. An answer file
+/// for a part that isn't listed is a compile error, so a misspelt one can't go untested.
+///
+/// ```ignore
+/// aoc_macros::example_tests! {
+///     part_one: crate::part_one::part_one,
+///     part_two: |input| crate::part_two::part_two(input),
+/// }
+/// ```
+///
+/// The files are read when the crate is compiled, so the crate needs a build script telling cargo
+/// to `rerun-if-changed=examples` for new files to get picked up.
+#[proc_macro]
+pub fn example_tests(input: TokenStream) -> TokenStream {
+    examples::example_tests(input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Registers a function as the solution to a puzzle part, `#[aoc(year, day, part)]`, so the runner
+/// and [`aoc::main!`] find it without a list of solutions to keep up to date.
+///
+/// The part has to be 1 or 2, and each part can only be registered once, which the runner checks
+/// when it collects them.
+///
+/// The function takes the puzzle input as a `&str` and returns a `Result<aoc::answer::Answer, E>`,
+/// where `E` turns into an `aoc::solution::SolveError`: that itself for parts with lines of input
+/// that might not parse, or just an `aoc::answer::Overflow` for the rest. If the day has an
+/// [`macro@aoc_generator`], it takes a reference to what that makes instead, or a slice if that's a
+/// `Vec`. The input is read from `src/input.txt` of the crate unless `input = "<path>"` says
+/// otherwise, and a function giving the part's [`aoc::explain::Explanation`] can be registered with
+/// it with `explain = <function>`.
+///
+/// ```ignore
+/// #[aoc(2023, 4, 2, explain = explain_part_two)]
+/// pub fn part_two(input: &str) -> Result<Answer, SolveError> {
+/// ```
+///
+/// The crate using this needs to depend on `aoc`.
+#[proc_macro_attribute]
+pub fn aoc(attr: TokenStream, item: TokenStream) -> TokenStream {
+    solution::aoc(attr.into(), item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Registers a function as what parses a day's input, `#[aoc_generator(year, day)]`, for parts that
+/// would otherwise both parse it themselves.
+///
+/// When both parts are run together the input is only parsed once, and each gets a reference to
+/// what the generator made. It has to own what it makes, rather than borrowing from the input.
+///
+/// ```ignore
+/// #[aoc_generator(2020, 1)]
+/// pub fn entries(input: &str) -> Vec<u64> {
+///     input.lines().map(|line| line.parse().unwrap()).collect()
+/// }
+///
+/// #[aoc(2020, 1, 1)]
+/// pub fn part_one(entries: &[u64]) -> Result<Answer, Overflow> {
+/// ```
+#[proc_macro_attribute]
+pub fn aoc_generator(attr: TokenStream, item: TokenStream) -> TokenStream {
+    solution::aoc_generator(attr.into(), item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Registers a function as the check of a day's input, `#[aoc_validator(year, day)]`, for the
+/// runner's `validate` to find.
+///
+/// It takes the puzzle input and returns an `aoc::validate::Report` of the lines that don't parse
+/// and the ones that parse but look wrong, rather than panicking at the first of them.
+///
+/// ```ignore
+/// #[aoc_validator(2023, 4)]
+/// pub fn validate(input: &str) -> Report {
+/// ```
+#[proc_macro_attribute]
+pub fn aoc_validator(attr: TokenStream, item: TokenStream) -> TokenStream {
+    solution::aoc_validator(attr.into(), item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derives `aoc::parse::Parse` from the format of the text, `#[format("Game {game_num}: {hands}")]`,
+/// parsing each field where the format has it with its own type's `Parse`.
+///
+/// ```ignore
+/// #[derive(Parse)]
+/// #[format("Card {card_num}: {winning_numbers} | {my_numbers}")]
+/// pub struct ScratchoffCard {
+///     pub card_num: usize,
+///     #[format(sep = " ", with = lottery_number)]
+///     pub winning_numbers: LotteryNums,
+///     #[format(sep = " ", with = lottery_number)]
+///     pub my_numbers: LotteryNums,
+/// }
+/// ```
+///
+/// See `aoc::parse` for what the format and fields' `#[format(...)]`s can say. The crate using this
+/// needs to depend on `aoc`, which re-exports it alongside the trait.
+#[proc_macro_derive(Parse, attributes(format))]
+pub fn derive_parse(input: TokenStream) -> TokenStream {
+    parse::derive_parse(input.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,296 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    spanned::Spanned, Attribute, Data, DeriveInput, Expr, Fields, GenericArgument, Ident, Lifetime,
+    LitStr, PathArguments, Type,
+};
+
+use crate::solution::is_str;
+
+/// A bit of a format string: text that has to be there, or the field that goes there.
+enum Piece {
+    Text(String),
+    Field(String),
+}
+
+/// Splits `"Game {game_num}: {hands}"` into its text and fields, with `{{` and `}}` for braces.
+fn pieces(format: &LitStr) -> syn::Result<Vec<Piece>> {
+    let error = |message: &str| syn::Error::new(format.span(), message);
+    let mut pieces = vec![];
+    let mut text = String::new();
+    let mut chars = format
+        .value()
+        .chars()
+        .collect::<Vec<_>>()
+        .into_iter()
+        .peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => text.push(chars.next().unwrap()),
+            '}' if chars.peek() == Some(&'}') => text.push(chars.next().unwrap()),
+            '{' => {
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => name.push(c),
+                        None => {
+                            return Err(error("unclosed `{` in the format, write `{{` for a brace"))
+                        }
+                    }
+                }
+                if name.is_empty() {
+                    return Err(error(
+                        "fields in the format need naming, like `{count}` or `{0}`",
+                    ));
+                }
+                if !text.is_empty() {
+                    pieces.push(Piece::Text(std::mem::take(&mut text)));
+                }
+                pieces.push(Piece::Field(name.trim().to_string()));
+            }
+            '}' => return Err(error("unmatched `}` in the format, write `}}` for a brace")),
+            c => text.push(c),
+        }
+    }
+    if !text.is_empty() {
+        pieces.push(Piece::Text(text));
+    }
+    Ok(pieces)
+}
+
+/// The `#[format(...)]` on a field.
+#[derive(Default)]
+struct FieldFormat {
+    sep: Option<LitStr>,
+    item: Option<Type>,
+    recognize: bool,
+    with: Option<Expr>,
+}
+
+impl FieldFormat {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut format = FieldFormat::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("format")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("sep") {
+                    format.sep = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("item") {
+                    format.item = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("recognize") {
+                    format.recognize = true;
+                } else if meta.path.is_ident("with") {
+                    format.with = Some(meta.value()?.parse()?);
+                } else {
+                    return Err(meta.error("expected `sep`, `item`, `recognize` or `with`"));
+                }
+                Ok(())
+            })?;
+        }
+        if format.sep.is_none() && (format.item.is_some() || format.recognize) {
+            return Err(syn::Error::new(
+                attrs[0].span(),
+                "`item` and `recognize` are for fields with a `sep`",
+            ));
+        }
+        Ok(format)
+    }
+}
+
+/// The `#[format("...")]` on a struct or variant.
+fn format_of(attrs: &[Attribute], span: Span) -> syn::Result<LitStr> {
+    attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("format"))
+        .ok_or_else(|| syn::Error::new(span, "expected a `#[format(\"...\")]` to parse it with"))?
+        .parse_args()
+}
+
+/// `T` of a `Vec<T>`, or of any type with just the one type parameter.
+fn item_of(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else { return None };
+    let PathArguments::AngleBracketed(args) = &path.path.segments.last()?.arguments else {
+        return None;
+    };
+    let mut types = args.args.iter().filter_map(|arg| match arg {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    });
+    match (types.next(), types.next()) {
+        (Some(ty), None) => Some(ty),
+        _ => None,
+    }
+}
+
+/// Parses `fields` in the order `format` has them, into `constructor`.
+fn parse_fields(
+    constructor: TokenStream,
+    fields: &Fields,
+    format: &LitStr,
+    lifetime: &Lifetime,
+) -> syn::Result<TokenStream> {
+    let nom = quote!(::aoc::parse::nom);
+    let named = fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| match &field.ident {
+            Some(ident) => (ident.to_string(), ident.clone(), field),
+            None => (i.to_string(), format_ident!("field_{i}"), field),
+        })
+        .collect::<Vec<_>>();
+
+    let pieces = pieces(format)?;
+    let mut parsed = vec![];
+    let mut steps = vec![];
+    for (i, piece) in pieces.iter().enumerate() {
+        let name = match piece {
+            Piece::Text(text) => {
+                steps.push(quote!(let (input, _) = #nom::bytes::complete::tag(#text)(input)?;));
+                continue;
+            }
+            Piece::Field(name) => name,
+        };
+        let Some((_, binding, field)) = named.iter().find(|(field, ..)| field == name) else {
+            return Err(syn::Error::new(
+                format.span(),
+                format!("there's no field `{name}` to parse into"),
+            ));
+        };
+        if parsed.contains(binding) {
+            return Err(syn::Error::new(
+                format.span(),
+                format!("`{name}` is in the format more than once"),
+            ));
+        }
+        parsed.push(binding.clone());
+
+        let ty = &field.ty;
+        let field_format = FieldFormat::parse(&field.attrs)?;
+        let parser = match (&field_format.sep, &field_format.with) {
+            (None, Some(with)) => quote!(#with),
+            (None, None) if is_str(ty) => {
+                match pieces.get(i + 1) {
+                    Some(Piece::Text(until)) => quote!(#nom::bytes::complete::take_until(#until)),
+                    Some(Piece::Field(_)) => return Err(syn::Error::new(
+                        field.span(),
+                        "a `&str` takes everything up to the text after it in the format, which \
+                         needs some",
+                    )),
+                    None => quote!(#nom::combinator::rest),
+                }
+            }
+            (None, None) => quote!(<#ty as ::aoc::parse::Parse<#lifetime>>::parse),
+            (Some(sep), with) => {
+                let item = match (&field_format.item, with) {
+                    (_, Some(with)) => quote!(#with),
+                    (Some(item), None) => quote!(<#item as ::aoc::parse::Parse<#lifetime>>::parse),
+                    (None, None) => match item_of(ty) {
+                        Some(item) => quote!(<#item as ::aoc::parse::Parse<#lifetime>>::parse),
+                        None => {
+                            return Err(syn::Error::new(
+                                ty.span(),
+                                "say what this is a list of with `item = Type`",
+                            ))
+                        }
+                    },
+                };
+                if field_format.recognize {
+                    quote! {
+                        #nom::combinator::map(
+                            ::aoc::parse::recognize_separated(#item, #sep),
+                            <#ty as ::core::convert::From<&#lifetime str>>::from,
+                        )
+                    }
+                } else {
+                    quote!(::aoc::parse::separated::<_, #ty>(#item, #sep))
+                }
+            }
+        };
+        steps.push(quote!(let (input, #binding) = #parser(input)?;));
+    }
+
+    if let Some((name, ..)) = named
+        .iter()
+        .find(|(_, binding, _)| !parsed.contains(binding))
+    {
+        return Err(syn::Error::new(
+            format.span(),
+            format!("the format has no `{{{name}}}` to parse that field from"),
+        ));
+    }
+    let bindings = named.iter().map(|(_, binding, _)| binding);
+    let construct = match fields {
+        Fields::Named(_) => quote!(#constructor { #(#bindings),* }),
+        Fields::Unnamed(_) => quote!(#constructor(#(#bindings),*)),
+        Fields::Unit => quote!(#constructor),
+    };
+    Ok(quote! {
+        #(#steps)*
+        ::core::result::Result::Ok((input, #construct))
+    })
+}
+
+pub fn derive_parse(input: TokenStream) -> syn::Result<TokenStream> {
+    let input: DeriveInput = syn::parse2(input)?;
+    let name = &input.ident;
+
+    // What's parsed can borrow from the input for the type's lifetime, if it has one.
+    let mut generics = input.generics.clone();
+    let lifetime = match input.generics.lifetimes().next() {
+        Some(param) => param.lifetime.clone(),
+        None => {
+            let lifetime = Lifetime::new("'input", Span::call_site());
+            generics.params.insert(0, syn::parse_quote!(#lifetime));
+            lifetime
+        }
+    };
+    let (impl_generics, _, _) = generics.split_for_impl();
+    let (_, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let format = format_of(&input.attrs, name.span())?;
+            parse_fields(quote!(Self), &data.fields, &format, &lifetime)?
+        }
+        Data::Enum(data) => {
+            let variants = data
+                .variants
+                .iter()
+                .map(|variant| {
+                    let format = format_of(&variant.attrs, variant.ident.span())?;
+                    let ident: &Ident = &variant.ident;
+                    let body = parse_fields(quote!(Self::#ident), &variant.fields, &format, &lifetime)?;
+                    Ok(quote!(|input: &#lifetime str| -> ::aoc::parse::nom::IResult<&#lifetime str, Self> { #body }))
+                })
+                .collect::<syn::Result<Vec<_>>>()?;
+            let count = variants.len();
+            quote! {
+                let variants: [fn(&#lifetime str) -> ::aoc::parse::nom::IResult<&#lifetime str, Self>; #count] =
+                    [#(#variants),*];
+                for variant in variants {
+                    match variant(input) {
+                        ::core::result::Result::Err(::aoc::parse::nom::Err::Error(_)) => continue,
+                        parsed => return parsed,
+                    }
+                }
+                ::core::result::Result::Err(::aoc::parse::nom::Err::Error(
+                    ::aoc::parse::nom::error::Error::new(input, ::aoc::parse::nom::error::ErrorKind::Alt),
+                ))
+            }
+        }
+        Data::Union(_) => {
+            return Err(syn::Error::new(
+                name.span(),
+                "only structs and enums can be parsed",
+            ))
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::aoc::parse::Parse<#lifetime> for #name #ty_generics #where_clause {
+            fn parse(input: &#lifetime str) -> ::aoc::parse::nom::IResult<&#lifetime str, Self> {
+                #body
+            }
+        }
+    })
+}
@@ -0,0 +1,191 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{punctuated::Punctuated, spanned::Spanned, Expr, FnArg, ItemFn, Lit, Token, Type};
+
+/// The attribute's arguments: some numbers, then `name = value`s.
+struct Args {
+    numbers: Vec<syn::LitInt>,
+    named: Vec<(syn::Ident, Expr)>,
+}
+
+impl Args {
+    fn parse(attr: TokenStream, numbers: &[&str]) -> syn::Result<Self> {
+        let usage = format!("expected `{}`", numbers.join(", "));
+        let exprs =
+            syn::parse::Parser::parse2(Punctuated::<Expr, Token![,]>::parse_terminated, attr)?;
+        let mut args = Args {
+            numbers: vec![],
+            named: vec![],
+        };
+        for expr in exprs {
+            match expr {
+                Expr::Lit(syn::ExprLit {
+                    lit: Lit::Int(number),
+                    ..
+                }) if args.named.is_empty() => args.numbers.push(number),
+                Expr::Assign(assign) => match *assign.left {
+                    Expr::Path(path) if path.path.get_ident().is_some() => {
+                        let name = path.path.get_ident().unwrap().clone();
+                        args.named.push((name, *assign.right));
+                    }
+                    left => return Err(syn::Error::new(left.span(), "expected a name")),
+                },
+                expr => return Err(syn::Error::new(expr.span(), usage)),
+            }
+        }
+        if args.numbers.len() != numbers.len() {
+            return Err(syn::Error::new(Span::call_site(), usage));
+        }
+        Ok(args)
+    }
+
+    fn take(&mut self, name: &str) -> Option<Expr> {
+        let index = self.named.iter().position(|(ident, _)| ident == name)?;
+        Some(self.named.remove(index).1)
+    }
+
+    /// Complains about names nothing asked for.
+    fn finish(self) -> syn::Result<()> {
+        match self.named.first() {
+            Some((name, _)) => Err(syn::Error::new(
+                name.span(),
+                format!("unexpected argument `{name}`"),
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Whether a type is `&str`.
+pub(crate) fn is_str(ty: &Type) -> bool {
+    match ty {
+        Type::Reference(reference) => {
+            matches!(&*reference.elem, Type::Path(path) if path.path.is_ident("str"))
+        }
+        _ => false,
+    }
+}
+
+pub fn aoc(attr: TokenStream, item: TokenStream) -> syn::Result<TokenStream> {
+    let mut args = Args::parse(attr, &["year", "day", "part"])?;
+    let function: ItemFn = syn::parse2(item)?;
+    let [year, day, part] = [0, 1, 2].map(|i| args.numbers[i].clone());
+    if !matches!(part.base10_parse::<u8>(), Ok(1 | 2)) {
+        return Err(syn::Error::new(
+            part.span(),
+            "puzzles only have parts 1 and 2",
+        ));
+    }
+    let name = &function.sig.ident;
+
+    let input = args
+        .take("input")
+        .map(|input| quote!(#input))
+        .unwrap_or_else(|| quote!("src/input.txt"));
+    let explain = match args.take("explain") {
+        Some(explain) => quote!(::core::option::Option::Some(#explain)),
+        None => quote!(::core::option::Option::None),
+    };
+    args.finish()?;
+
+    let takes = match function.sig.inputs.iter().collect::<Vec<_>>()[..] {
+        [FnArg::Typed(arg)] => &*arg.ty,
+        _ => return Err(syn::Error::new(
+            function.sig.inputs.span(),
+            "a part takes one argument, the input or what its day's #[aoc_generator] made of it",
+        )),
+    };
+    let solve = if is_str(takes) {
+        quote! {
+            ::aoc::solution::Solve::Input(|input| {
+                #name(input).map_err(::core::convert::Into::into)
+            })
+        }
+    } else {
+        // Generated input comes to us as `&dyn Any`, and has to be whatever the generator made.
+        // Parts can take a slice of the `Vec` it made, like they would if they called it.
+        let generated = match takes {
+            Type::Reference(reference) => match &*reference.elem {
+                Type::Slice(slice) => {
+                    let elem = &slice.elem;
+                    quote!(::std::vec::Vec<#elem>)
+                }
+                generated => quote!(#generated),
+            },
+            _ => {
+                return Err(syn::Error::new(
+                    takes.span(),
+                    "expected `&str`, or a reference to what the day's #[aoc_generator] makes",
+                ))
+            }
+        };
+        quote! {
+            ::aoc::solution::Solve::Generated(|generated| {
+                #name(generated.downcast_ref::<#generated>().expect(concat!(
+                    "the day's #[aoc_generator] should make a ",
+                    stringify!(#generated),
+                )))
+                .map_err(::core::convert::Into::into)
+            })
+        }
+    };
+
+    Ok(quote! {
+        #function
+
+        ::aoc::solution::inventory::submit! {
+            ::aoc::solution::Solution {
+                year: #year,
+                day: #day,
+                part: #part,
+                package: env!("CARGO_PKG_NAME"),
+                crate_dir: env!("CARGO_MANIFEST_DIR"),
+                input: #input,
+                solve: #solve,
+                explain: #explain,
+            }
+        }
+    })
+}
+
+pub fn aoc_generator(attr: TokenStream, item: TokenStream) -> syn::Result<TokenStream> {
+    let args = Args::parse(attr, &["year", "day"])?;
+    let function: ItemFn = syn::parse2(item)?;
+    let [year, day] = [0, 1].map(|i| args.numbers[i].clone());
+    args.finish()?;
+    let name = &function.sig.ident;
+
+    Ok(quote! {
+        #function
+
+        ::aoc::solution::inventory::submit! {
+            ::aoc::solution::Generator {
+                year: #year,
+                day: #day,
+                generate: |input| {
+                    ::std::boxed::Box::new(#name(input)) as ::std::boxed::Box<dyn ::std::any::Any>
+                },
+            }
+        }
+    })
+}
+
+pub fn aoc_validator(attr: TokenStream, item: TokenStream) -> syn::Result<TokenStream> {
+    let args = Args::parse(attr, &["year", "day"])?;
+    let function: ItemFn = syn::parse2(item)?;
+    let [year, day] = [0, 1].map(|i| args.numbers[i].clone());
+    args.finish()?;
+    let name = &function.sig.ident;
+
+    Ok(quote! {
+        #function
+
+        ::aoc::solution::inventory::submit! {
+            ::aoc::validate::Validator {
+                year: #year,
+                day: #day,
+                validate: #name,
+            }
+        }
+    })
+}
@@ -0,0 +1,508 @@
+use std::fmt;
+
+use crate::ocr;
+
+/// A puzzle's answer: a number, some text, or letters drawn out in a grid of pixels.
+///
+/// Numbers are kept in a `u64` until they don't fit and in a `u128` after that, with negative
+/// ones in an `i128`. Adding up plain integers wraps silently in release builds, which our scaled
+/// up inputs are big enough to hit. Accumulating answers instead checks every step, and gives up
+/// with an [`Overflow`] once the answer doesn't fit in 128 bits either. There's no bigint past
+/// that: an answer that big is an error rather than something we'd type into the site.
+#[derive(Debug, Clone)]
+pub enum Answer {
+    /// Most answers fit here, and adding these is as quick as adding plain `u64`s.
+    Narrow(u64),
+    /// Where answers go once they don't fit in a `u64`.
+    Wide(u128),
+    /// Negative answers, anything at or above zero is one of the unsigned ones.
+    Signed(i128),
+    /// Answers that aren't numbers, like a password or the order some steps happen in.
+    Text(String),
+    /// Letters drawn with `#`s, a row per line, for the puzzles that have us read them off a
+    /// screen.
+    Letters(String),
+}
+
+/// Which operation overflowed.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Operation {
+    Add,
+    Multiply,
+}
+
+/// An answer that didn't fit, with what we were doing when it stopped fitting.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Overflow {
+    pub operation: Operation,
+    pub lhs: Answer,
+    pub rhs: Answer,
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let operator = match self.operation {
+            Operation::Add => '+',
+            Operation::Multiply => '*',
+        };
+        let int = if self.lhs.unsigned().is_some() && self.rhs.unsigned().is_some() {
+            "a u128"
+        } else {
+            "an i128"
+        };
+        write!(
+            f,
+            "{} {operator} {} doesn't fit in {int}",
+            self.lhs, self.rhs
+        )
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+/// Adds `u128`s, for working that's kept in plain integers rather than [`Answer`]s, like counts
+/// in an array, but should overflow the way answers do.
+///
+/// ```
+/// use aoc::answer::checked_add;
+/// assert_eq!(checked_add(1, 2), Ok(3));
+/// assert!(checked_add(u128::MAX, 1).is_err());
+/// ```
+pub fn checked_add(lhs: u128, rhs: u128) -> Result<u128, Overflow> {
+    lhs.checked_add(rhs).ok_or(Overflow {
+        operation: Operation::Add,
+        lhs: lhs.into(),
+        rhs: rhs.into(),
+    })
+}
+
+/// Multiplies `u128`s, like [`checked_add`] adds them.
+pub fn checked_mul(lhs: u128, rhs: u128) -> Result<u128, Overflow> {
+    lhs.checked_mul(rhs).ok_or(Overflow {
+        operation: Operation::Multiply,
+        lhs: lhs.into(),
+        rhs: rhs.into(),
+    })
+}
+
+impl Answer {
+    pub const ZERO: Answer = Answer::Narrow(0);
+    pub const ONE: Answer = Answer::Narrow(1);
+
+    /// Letters from the rows of pixels they're drawn in, ignoring the trailing blank pixels some
+    /// screens leave.
+    ///
+    /// ```
+    /// use aoc::answer::Answer;
+    /// let answer = Answer::letters(["#..#.  ", "####.", "#..#."]);
+    /// assert_eq!(answer.to_string(), "#..#\n####\n#..#");
+    /// ```
+    pub fn letters<R: AsRef<str>>(rows: impl IntoIterator<Item = R>) -> Answer {
+        let rows = rows
+            .into_iter()
+            .map(|row| row.as_ref().trim_end_matches([' ', '.']).to_string())
+            .collect::<Vec<_>>();
+        let width = rows
+            .iter()
+            .map(|row| row.chars().count())
+            .max()
+            .unwrap_or(0);
+        let rows = rows
+            .into_iter()
+            .map(|row| format!("{row:.<width$}"))
+            .collect::<Vec<_>>();
+        Answer::Letters(rows.join("\n"))
+    }
+
+    /// The answer on one line, the way the registry keeps it and the submit form takes it.
+    ///
+    /// That's what `Display` gives, except for letters, which are read with [`ocr`], or if they
+    /// can't be, have their rows joined with `/`s.
+    pub fn to_line(&self) -> String {
+        match self {
+            Answer::Letters(pixels) => {
+                ocr::read(pixels).unwrap_or_else(|_| pixels.replace('\n', "/"))
+            }
+            answer => answer.to_string(),
+        }
+    }
+
+    fn unsigned(&self) -> Option<u128> {
+        match *self {
+            Answer::Narrow(answer) => Some(answer.into()),
+            Answer::Wide(answer) => Some(answer),
+            Answer::Signed(answer) => answer.try_into().ok(),
+            _ => None,
+        }
+    }
+
+    /// The answer as an `i128`, if it's a number that fits in one.
+    ///
+    /// ```
+    /// use aoc::answer::Answer;
+    /// assert_eq!(Answer::from(-7).to_i128(), Some(-7));
+    /// assert_eq!(Answer::from(u128::MAX).to_i128(), None);
+    /// assert_eq!(Answer::from("seven").to_i128(), None);
+    /// ```
+    pub fn to_i128(&self) -> Option<i128> {
+        self.signed()
+    }
+
+    fn signed(&self) -> Option<i128> {
+        match *self {
+            Answer::Signed(answer) => Some(answer),
+            _ => self.unsigned().and_then(|answer| answer.try_into().ok()),
+        }
+    }
+
+    fn is_number(&self) -> bool {
+        !matches!(self, Answer::Text(_) | Answer::Letters(_))
+    }
+
+    /// Does the arithmetic in `u128`s if it can, and in `i128`s if either side is negative.
+    fn checked(
+        self,
+        other: Answer,
+        operation: Operation,
+        unsigned: fn(u128, u128) -> Option<u128>,
+        signed: fn(i128, i128) -> Option<i128>,
+    ) -> Result<Answer, Overflow> {
+        assert!(
+            self.is_number() && other.is_number(),
+            "can't do arithmetic with {self:?} and {other:?}"
+        );
+        let result = match (self.unsigned(), other.unsigned()) {
+            (Some(lhs), Some(rhs)) => unsigned(lhs, rhs).map(Answer::from),
+            _ => self
+                .signed()
+                .zip(other.signed())
+                .and_then(|(lhs, rhs)| signed(lhs, rhs))
+                .map(Answer::from),
+        };
+        result.ok_or(Overflow {
+            operation,
+            lhs: self,
+            rhs: other,
+        })
+    }
+
+    /// # Panics
+    ///
+    /// If either side isn't a number.
+    ///
+    /// ```
+    /// use aoc::answer::Answer;
+    /// let big = Answer::from(u64::MAX);
+    /// assert_eq!(big.checked_add(1u64).unwrap(), Answer::from(1u128 << 64));
+    /// assert_eq!(Answer::from(5).checked_add(-8).unwrap(), -3);
+    /// assert!(Answer::from(u128::MAX).checked_add(1u64).is_err());
+    /// ```
+    pub fn checked_add(self, other: impl Into<Answer>) -> Result<Answer, Overflow> {
+        let other = other.into();
+        if let (Answer::Narrow(lhs), Answer::Narrow(rhs)) = (&self, &other) {
+            if let Some(sum) = lhs.checked_add(*rhs) {
+                return Ok(Answer::Narrow(sum));
+            }
+        }
+        self.checked(other, Operation::Add, u128::checked_add, i128::checked_add)
+    }
+
+    /// # Panics
+    ///
+    /// If either side isn't a number.
+    pub fn checked_mul(self, other: impl Into<Answer>) -> Result<Answer, Overflow> {
+        let other = other.into();
+        if let (Answer::Narrow(lhs), Answer::Narrow(rhs)) = (&self, &other) {
+            if let Some(product) = lhs.checked_mul(*rhs) {
+                return Ok(Answer::Narrow(product));
+            }
+        }
+        self.checked(
+            other,
+            Operation::Multiply,
+            u128::checked_mul,
+            i128::checked_mul,
+        )
+    }
+}
+
+impl Default for Answer {
+    fn default() -> Self {
+        Answer::ZERO
+    }
+}
+
+impl PartialEq for Answer {
+    /// Numbers are equal by value, whichever way they're stored.
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Text(lhs), Answer::Text(rhs)) => lhs == rhs,
+            (Answer::Letters(lhs), Answer::Letters(rhs)) => lhs == rhs,
+            _ => match (self.signed(), other.signed()) {
+                (Some(lhs), Some(rhs)) => lhs == rhs,
+                _ => self.unsigned().is_some() && self.unsigned() == other.unsigned(),
+            },
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+/// Comparing with an answer from the registry or an example's expected answer.
+///
+/// Numbers are compared by value, so `"+7"` and `"007"` are both 7. Letters can be given as the
+/// letters they read as, or drawn out with a row per line or with the rows joined with `/`s.
+///
+/// ```
+/// use aoc::answer::Answer;
+/// assert_eq!(Answer::from(-12), "-12");
+/// assert_eq!(Answer::from("abcde"), "abcde");
+/// assert_eq!(Answer::letters(["#.", "##"]), "#./##");
+/// ```
+impl PartialEq<str> for Answer {
+    fn eq(&self, registered: &str) -> bool {
+        let registered = registered.trim();
+        match self {
+            Answer::Text(text) => text == registered,
+            Answer::Letters(pixels) => {
+                ocr::read(pixels).is_ok_and(|letters| letters == registered)
+                    || *self == Answer::letters(registered.split(['\n', '/']))
+            }
+            _ => registered
+                .parse::<i128>()
+                .map(Answer::from)
+                .or_else(|_| registered.parse::<u128>().map(Answer::from))
+                .is_ok_and(|registered| *self == registered),
+        }
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, registered: &&str) -> bool {
+        *self == **registered
+    }
+}
+
+macro_rules! unsigned {
+    ($($int:ty)*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(answer: $int) -> Self {
+                    Answer::Narrow(answer as u64)
+                }
+            }
+
+            impl PartialEq<$int> for Answer {
+                fn eq(&self, other: &$int) -> bool {
+                    self.unsigned() == Some(*other as u128)
+                }
+            }
+        )*
+    };
+}
+
+// usize is at most 64 bits everywhere we run.
+unsigned!(u8 u16 u32 u64 usize);
+
+macro_rules! signed {
+    ($($int:ty)*) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(answer: $int) -> Self {
+                    Answer::from(answer as i128)
+                }
+            }
+
+            impl PartialEq<$int> for Answer {
+                fn eq(&self, other: &$int) -> bool {
+                    self.signed() == Some(*other as i128)
+                }
+            }
+        )*
+    };
+}
+
+signed!(i8 i16 i32 i64 isize);
+
+impl From<u128> for Answer {
+    /// Narrow if it fits, so equal answers are stored the same way.
+    fn from(answer: u128) -> Self {
+        u64::try_from(answer).map_or(Answer::Wide(answer), Answer::Narrow)
+    }
+}
+
+impl From<i128> for Answer {
+    /// Only negative answers are kept signed.
+    fn from(answer: i128) -> Self {
+        u128::try_from(answer).map_or(Answer::Signed(answer), Answer::from)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(answer: String) -> Self {
+        Answer::Text(answer)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(answer: &str) -> Self {
+        Answer::Text(answer.to_string())
+    }
+}
+
+impl From<char> for Answer {
+    fn from(answer: char) -> Self {
+        Answer::Text(answer.to_string())
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Narrow(answer) => answer.fmt(f),
+            Answer::Wide(answer) => answer.fmt(f),
+            Answer::Signed(answer) => answer.fmt(f),
+            Answer::Text(answer) | Answer::Letters(answer) => answer.fmt(f),
+        }
+    }
+}
+
+/// Summing and multiplying up answers, checking for overflow as we go.
+pub trait Accumulate: Iterator + Sized
+where
+    Self::Item: Into<Answer>,
+{
+    /// ```
+    /// use aoc::answer::Accumulate;
+    /// let sum = [u64::MAX, 1].into_iter().sum_answers().unwrap();
+    /// assert_eq!(sum.to_string(), "18446744073709551616");
+    /// ```
+    fn sum_answers(mut self) -> Result<Answer, Overflow> {
+        self.try_fold(Answer::ZERO, Answer::checked_add)
+    }
+
+    fn product_answers(mut self) -> Result<Answer, Overflow> {
+        self.try_fold(Answer::ONE, Answer::checked_mul)
+    }
+}
+
+impl<I> Accumulate for I
+where
+    I: Iterator,
+    I::Item: Into<Answer>,
+{
+}
+
+mod test {
+
+    #[test]
+    fn widens_instead_of_wrapping() {
+        use crate::answer::{Accumulate, Answer};
+
+        let sum = std::iter::repeat(u64::MAX).take(3).sum_answers().unwrap();
+        assert!(matches!(sum, Answer::Wide(_)));
+        assert_eq!(sum, Answer::from(3 * u128::from(u64::MAX)));
+
+        let product = [u64::MAX, u64::MAX].into_iter().product_answers().unwrap();
+        assert_eq!(
+            product.to_string(),
+            (u128::from(u64::MAX) * u128::from(u64::MAX)).to_string()
+        );
+    }
+
+    #[test]
+    fn stays_narrow_when_it_fits() {
+        use crate::answer::{Accumulate, Answer};
+
+        let sum = (1..=100usize).sum_answers().unwrap();
+        assert!(matches!(sum, Answer::Narrow(5050)));
+        assert_eq!(sum, 5050usize);
+        assert_eq!(Answer::from(5u128), Answer::Narrow(5));
+        assert_eq!(Answer::Wide(5), Answer::Narrow(5));
+    }
+
+    #[test]
+    fn reports_overflow() {
+        use crate::answer::{Accumulate, Operation, Overflow};
+
+        let overflow = [u128::MAX, 2].into_iter().sum_answers().unwrap_err();
+        assert_eq!(
+            overflow,
+            Overflow {
+                operation: Operation::Add,
+                lhs: u128::MAX.into(),
+                rhs: 2u128.into()
+            }
+        );
+        assert_eq!(
+            overflow.to_string(),
+            "340282366920938463463374607431768211455 + 2 doesn't fit in a u128"
+        );
+        let overflow = [1u128 << 64, 1 << 64]
+            .into_iter()
+            .product_answers()
+            .unwrap_err();
+        assert_eq!(overflow.operation, Operation::Multiply);
+    }
+
+    #[test]
+    fn goes_negative() {
+        use crate::answer::{Accumulate, Answer};
+
+        let sum = [3i64, -5, 1].into_iter().sum_answers().unwrap();
+        assert!(matches!(sum, Answer::Signed(-1)));
+        assert_eq!(sum, -1i64);
+        assert_eq!(Answer::from(4i32), Answer::Narrow(4));
+        assert_eq!(Answer::from(-1i8).checked_mul(-1i8).unwrap(), 1u8);
+        assert_eq!(Answer::Signed(5), 5u64);
+        assert_ne!(Answer::from(u128::MAX), Answer::from(-1i128));
+
+        let overflow = Answer::from(u128::MAX).checked_add(-1i32).unwrap_err();
+        assert_eq!(
+            overflow.to_string(),
+            "340282366920938463463374607431768211455 + -1 doesn't fit in an i128"
+        );
+    }
+
+    #[test]
+    fn compares_with_registered_answers() {
+        use crate::answer::Answer;
+
+        assert_eq!(Answer::from(54630usize), "54630");
+        assert_eq!(Answer::from(54630usize), "054630\n");
+        assert_ne!(Answer::from(54630usize), "5463O");
+        assert_eq!(Answer::from(u128::MAX), u128::MAX.to_string().as_str());
+        assert_eq!(Answer::from(i128::MIN), i128::MIN.to_string().as_str());
+        assert_eq!(Answer::from("fgbdhcea"), "fgbdhcea");
+        assert_ne!(Answer::from("fgbdhcea"), "FGBDHCEA");
+        assert_ne!(Answer::from("12"), Answer::from(12u8));
+        assert_ne!(Answer::from(12u8), "twelve");
+    }
+
+    #[test]
+    fn draws_letters() {
+        use crate::answer::Answer;
+
+        let rows = [
+            ".##..###.. ",
+            "#..#.#..#. ",
+            "#..#.###.  ",
+            "####.#..#. ",
+            "#..#.#..#.",
+            "#..#.###.. ",
+        ];
+        let letters = Answer::letters(rows);
+        assert_eq!(letters.to_line(), "AB");
+        assert_eq!(letters, "AB");
+        assert_eq!(letters, rows.join("/").as_str());
+        assert_eq!(letters, rows.join("\n").as_str());
+        insta::assert_snapshot!(letters, @"
+        .##..###.
+        #..#.#..#
+        #..#.###.
+        ####.#..#
+        #..#.#..#
+        #..#.###.
+        ");
+    }
+}
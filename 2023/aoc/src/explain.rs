@@ -0,0 +1,160 @@
+use std::{fmt, ops::RangeInclusive};
+
+use crate::answer::{Accumulate, Answer, Overflow};
+
+/// One line of input and what it added to the answer.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Step {
+    /// 1-based, like an editor shows it.
+    pub line: usize,
+    pub values: Vec<String>,
+    pub contribution: Answer,
+}
+
+/// A per-line trace of how a solver got to its answer, so we don't have to sprinkle `dbg!` around.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Explanation {
+    pub columns: Vec<&'static str>,
+    pub steps: Vec<Step>,
+}
+
+impl Explanation {
+    /// `columns` names the values each step records, the line and contribution columns are added for us.
+    pub fn new(columns: &[&'static str]) -> Self {
+        Self {
+            columns: columns.to_vec(),
+            steps: vec![],
+        }
+    }
+
+    pub fn push(&mut self, line: usize, values: Vec<String>, contribution: impl Into<Answer>) {
+        assert_eq!(
+            values.len(),
+            self.columns.len(),
+            "a step needs a value for every column"
+        );
+        self.steps.push(Step {
+            line,
+            values,
+            contribution: contribution.into(),
+        });
+    }
+
+    /// The answer the steps add up to, checked like the part's own answer is.
+    pub fn total(&self) -> Result<Answer, Overflow> {
+        self.steps
+            .iter()
+            .map(|step| step.contribution.clone())
+            .sum_answers()
+    }
+
+    /// Just the steps for the given lines.
+    ///
+    /// ```
+    /// use aoc::explain::Explanation;
+    /// let mut explanation = Explanation::new(&["card"]);
+    /// explanation.push(1, vec!["1".into()], 8);
+    /// explanation.push(2, vec!["2".into()], 2);
+    /// explanation.push(3, vec!["3".into()], 2);
+    /// assert_eq!(explanation.lines(2..=3).total().unwrap(), 4);
+    /// ```
+    pub fn lines(&self, lines: RangeInclusive<usize>) -> Self {
+        Self {
+            columns: self.columns.clone(),
+            steps: self
+                .steps
+                .iter()
+                .filter(|step| lines.contains(&step.line))
+                .cloned()
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Display for Explanation {
+    /// Renders the steps as a table, with the total of the shown steps at the bottom.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let header = std::iter::once("line")
+            .chain(self.columns.iter().copied())
+            .chain(std::iter::once("contribution"))
+            .map(String::from)
+            .collect::<Vec<_>>();
+        let rows = self
+            .steps
+            .iter()
+            .map(|step| {
+                std::iter::once(step.line.to_string())
+                    .chain(step.values.iter().cloned())
+                    .chain(std::iter::once(step.contribution.to_string()))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let widths = header
+            .iter()
+            .enumerate()
+            .map(|(column, name)| {
+                rows.iter()
+                    .map(|row| row[column].chars().count())
+                    .chain(std::iter::once(name.chars().count()))
+                    .max()
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>();
+
+        let write_row = |f: &mut fmt::Formatter<'_>, row: &[String]| {
+            let cells = row
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:<width$}"))
+                .collect::<Vec<_>>();
+            writeln!(f, "{}", cells.join(" │ ").trim_end())
+        };
+
+        write_row(f, &header)?;
+        let rule = widths
+            .iter()
+            .map(|width| "─".repeat(*width))
+            .collect::<Vec<_>>();
+        writeln!(f, "{}", rule.join("─┼─"))?;
+        for row in &rows {
+            write_row(f, row)?;
+        }
+        match self.total() {
+            Ok(total) => write!(f, "total: {total}"),
+            Err(overflow) => write!(f, "total: overflowed, {overflow}"),
+        }
+    }
+}
+
+mod test {
+    #[test]
+    fn snapshot_table() {
+        use crate::explain::Explanation;
+
+        let mut explanation = Explanation::new(&["first", "last", "word"]);
+        explanation.push(1, vec!["1".into(), "2".into(), "".into()], 12);
+        explanation.push(2, vec!["2".into(), "9".into(), "nine".into()], 29);
+
+        insta::assert_snapshot!(explanation.to_string(), @r###"
+        line │ first │ last │ word │ contribution
+        ─────┼───────┼──────┼──────┼─────────────
+        1    │ 1     │ 2    │      │ 12
+        2    │ 2     │ 9    │ nine │ 29
+        total: 41
+        "###);
+    }
+
+    #[test]
+    fn total_overflows() {
+        use crate::explain::Explanation;
+
+        let mut explanation = Explanation::new(&[]);
+        explanation.push(1, vec![], u128::MAX);
+        explanation.push(2, vec![], 1u8);
+        assert!(explanation.total().is_err());
+        assert!(explanation.to_string().ends_with(
+            "total: overflowed, 340282366920938463463374607431768211455 + 1 doesn't fit in a u128"
+        ));
+    }
+}
@@ -0,0 +1,152 @@
+use std::{fmt, fs, io, ops::Deref, path::Path};
+
+/// Puzzle input tidied up so parsers only ever see one shape of it.
+///
+/// Inputs come from files saved on different machines and from indented string literals in tests,
+/// so the same puzzle can turn up with a byte order mark, `\r\n` line endings, a trailing newline
+/// or two, or every line indented to line up with the test around it. Parsing an `Input` instead
+/// means none of that has to be handled, or forgotten, in every day's parser.
+///
+/// Only literals lose their indentation. In a file, leading spaces and blank rows can be part of
+/// the puzzle, like in a grid.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Input(String);
+
+impl Input {
+    /// Tidies up the contents of an input file:
+    ///
+    /// - a byte order mark at the start is dropped
+    /// - `\r\n` line endings become `\n`
+    /// - newlines at the end are dropped
+    ///
+    /// ```
+    /// use aoc::input::Input;
+    /// assert_eq!(Input::new("\u{feff}1abc2\r\npqr3stu8vwx\r\n\r\n").as_str(), "1abc2\npqr3stu8vwx");
+    /// assert_eq!(Input::new("  #\n\n # \n").as_str(), "  #\n\n # ");
+    /// ```
+    pub fn new(raw: &str) -> Self {
+        let raw = raw.strip_prefix('\u{feff}').unwrap_or(raw);
+        let lines = raw.lines().collect::<Vec<_>>();
+        Self(lines.join("\n").trim_end_matches('\n').to_string())
+    }
+
+    /// Tidies up an indented string literal in a test, like [`Input::new`] does a file, and also:
+    ///
+    /// - blank lines at the start and end are dropped
+    /// - indentation every line shares is dropped, blank lines aside, which are left empty
+    ///
+    /// ```
+    /// use aoc::input::Input;
+    /// let input = Input::literal(
+    ///     "
+    ///     Game 1: 3 blue
+    ///       Game 2: 1 red
+    ///     ",
+    /// );
+    /// assert_eq!(input.as_str(), "Game 1: 3 blue\n  Game 2: 1 red");
+    /// ```
+    pub fn literal(raw: &str) -> Self {
+        let tidied = Self::new(raw);
+        let is_blank = |line: &&str| line.trim().is_empty();
+        let mut lines = tidied.lines().skip_while(is_blank).collect::<Vec<_>>();
+        while lines.last().is_some_and(is_blank) {
+            lines.pop();
+        }
+
+        let indent = lines
+            .iter()
+            .filter(|line| !is_blank(line))
+            .map(|line| line.len() - line.trim_start_matches([' ', '\t']).len())
+            .min()
+            .unwrap_or(0);
+        let lines = lines
+            .into_iter()
+            .map(|line| if is_blank(&line) { "" } else { &line[indent..] })
+            .collect::<Vec<_>>();
+        Self(lines.join("\n"))
+    }
+
+    /// Reads and tidies up the input in a file.
+    pub fn read(path: &Path) -> io::Result<Self> {
+        fs::read_to_string(path).map(|raw| Self::new(&raw))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Deref for Input {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Input {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for Input {
+    fn from(raw: &str) -> Self {
+        Self::new(raw)
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+mod test {
+
+    #[test]
+    fn tidies_up_line_endings() {
+        use crate::input::Input;
+
+        assert_eq!(Input::new("a\r\nb\r\n").as_str(), "a\nb");
+        assert_eq!(Input::new("a\nb\n\n\n").as_str(), "a\nb");
+        assert_eq!(Input::new("a\n\nb").as_str(), "a\n\nb");
+        assert_eq!(Input::new("\u{feff}a").as_str(), "a");
+        // Only at the start does it mark the byte order.
+        assert_eq!(Input::new("a\u{feff}").as_str(), "a\u{feff}");
+        assert_eq!(Input::new("").as_str(), "");
+        assert_eq!(Input::literal("\n \n\t\n").as_str(), "");
+    }
+
+    /// Files keep their leading spaces and blank rows, which can be part of a grid.
+    #[test]
+    fn files_keep_their_indentation() {
+        use crate::input::Input;
+
+        assert_eq!(Input::new("  #.\n  .#").as_str(), "  #.\n  .#");
+        assert_eq!(Input::new("\n#\n   \n#\n").as_str(), "\n#\n   \n#");
+        assert_eq!(Input::new("\n \n\t\n").as_str(), "\n \n\t");
+    }
+
+    #[test]
+    fn drops_shared_indentation() {
+        use crate::input::Input;
+
+        let input = Input::literal(
+            "
+            Card 1: 41 48 | 83 86
+
+              Card 2: 13 32 | 61 30
+            Card 3:  1 21 | 69 82
+            ",
+        );
+        assert_eq!(
+            input.as_str(),
+            "Card 1: 41 48 | 83 86\n\n  Card 2: 13 32 | 61 30\nCard 3:  1 21 | 69 82"
+        );
+
+        // Inputs whose lines all start with spaces lose them, but ones where only some do don't.
+        assert_eq!(Input::literal("  [D]\n[N] [C]").as_str(), "  [D]\n[N] [C]");
+        assert_eq!(Input::literal("\t#.\n\t.#").as_str(), "#.\n.#");
+    }
+}
@@ -0,0 +1,39 @@
+// Lets what `#[derive(Parse)]` generates, which names `::aoc`, work in here too.
+extern crate self as aoc;
+
+pub mod answer;
+pub mod explain;
+pub mod input;
+pub mod ocr;
+pub mod parse;
+pub mod solution;
+pub mod validate;
+
+/// The day's puzzle input, read from `src/input.txt` of the crate this is used in, wherever that's
+/// used from, so tests, benches and binaries all agree on where it lives. It's tidied up into an
+/// [`input::Input`], like the runner does with the inputs it reads.
+#[macro_export]
+macro_rules! input {
+    () => {
+        $crate::input::Input::new(include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/input.txt"
+        )))
+    };
+}
+
+/// A `main` that prints the answers of every `#[aoc]` solution in the crate it's used in, for each
+/// day's puzzle input.
+///
+/// The binary has to use the crate's library for its solutions to be linked in, which
+/// `aoc::main!(day_one)` does by naming it.
+#[macro_export]
+macro_rules! main {
+    ($library:ident) => {
+        use $library as _;
+
+        fn main() {
+            $crate::solution::print_answers(env!("CARGO_PKG_NAME"));
+        }
+    };
+}
@@ -0,0 +1,294 @@
+//! Reading the capital letters some puzzles answer with by drawing them out in `#`s.
+//!
+//! There are two fonts: letters 4 pixels wide and 6 tall, and letters 6 wide and 10 tall. Which
+//! one it is goes by how tall the letters are, and the letters are told apart by the blank columns
+//! between them, so how wide the screen is and how much space there is around the letters doesn't
+//! matter.
+
+use std::{fmt, ops::Range};
+
+use grid::Grid;
+
+/// The 4x6 font, which doesn't have every letter, only the ones the puzzles have used.
+const SMALL: &[(char, [&str; 6])] = &[
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// The 6x10 font, again only the letters the puzzles have used.
+#[rustfmt::skip]
+const LARGE: &[(char, [&str; 10])] = &[
+    ('A', ["..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#"]),
+    ('B', ["#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#", "#....#", "#####."]),
+    ('C', [".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#....#", ".####."]),
+    ('E', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('F', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('G', [".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#", "#...##", ".###.#"]),
+    ('H', ["#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#", "#....#"]),
+    ('J', ["...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.", "#...#.", ".###.."]),
+    ('K', ["#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..", "#...#.", "#....#"]),
+    ('L', ["#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('N', ["#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##", "#...##", "#....#"]),
+    ('P', ["#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('R', ["#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.", "#....#", "#....#"]),
+    ('X', ["#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.", "#....#", "#....#"]),
+    ('Z', ["######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....", "#.....", "######"]),
+];
+
+/// Why some pixels couldn't be read.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ReadError {
+    /// Neither font is this tall, not counting blank rows above and below the letters.
+    Height(usize),
+    /// Some letters aren't in the font. `read` has a `?` for each of them, and `columns` says where
+    /// they are.
+    Unknown {
+        read: String,
+        columns: Vec<Range<usize>>,
+    },
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Height(height) => {
+                write!(f, "letters are 6 or 10 pixels tall, these are {height}")
+            }
+            ReadError::Unknown { read, columns } => {
+                let columns = columns
+                    .iter()
+                    .map(|columns| format!("{}-{}", columns.start, columns.end - 1))
+                    .collect::<Vec<_>>();
+                write!(
+                    f,
+                    "read {read:?}, but didn't know the letters in columns {}",
+                    columns.join(", ")
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ReadError {}
+
+/// Reads the letters drawn in some text, where `#` and `█` are lit and anything else isn't.
+///
+/// ```
+/// let screen = "
+/// ..#..#.####
+/// ..#..#.#...
+/// ..####.###.
+/// ..#..#.#...
+/// ..#..#.#...
+/// ..#..#.####
+/// ";
+/// assert_eq!(aoc::ocr::read(screen).unwrap(), "HE");
+/// ```
+pub fn read(text: &str) -> Result<String, ReadError> {
+    read_pixels(
+        text.lines()
+            .map(|line| line.chars().map(|c| c == '#' || c == '█').collect())
+            .collect(),
+    )
+}
+
+/// Reads the letters drawn in a grid, where `true` is lit.
+pub fn read_grid(grid: &Grid<bool>) -> Result<String, ReadError> {
+    read_pixels(grid.rows().map(<[bool]>::to_vec).collect())
+}
+
+fn read_pixels(mut rows: Vec<Vec<bool>>) -> Result<String, ReadError> {
+    let is_blank = |row: &Vec<bool>| !row.contains(&true);
+    while rows.last().is_some_and(is_blank) {
+        rows.pop();
+    }
+    let top = rows.iter().take_while(|row| is_blank(row)).count();
+    rows.drain(..top);
+
+    let font = match rows.len() {
+        6 => font(SMALL),
+        10 => font(LARGE),
+        height => return Err(ReadError::Height(height)),
+    };
+
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let lit = |x: usize| rows.iter().any(|row| row.get(x) == Some(&true));
+    let mut read = String::new();
+    let mut unknown = vec![];
+    let mut x = 0;
+    while x < width {
+        if !lit(x) {
+            x += 1;
+            continue;
+        }
+        let start = x;
+        while x < width && lit(x) {
+            x += 1;
+        }
+        let glyph = draw(&rows, start..x);
+        match font.iter().find(|(_, pixels)| *pixels == glyph) {
+            Some(&(letter, _)) => read.push(letter),
+            None => {
+                read.push('?');
+                unknown.push(start..x);
+            }
+        }
+    }
+
+    if unknown.is_empty() {
+        Ok(read)
+    } else {
+        Err(ReadError::Unknown {
+            read,
+            columns: unknown,
+        })
+    }
+}
+
+/// The font's letters drawn the way [`draw`] draws them, without the blank columns either side.
+fn font<const HEIGHT: usize>(font: &[(char, [&str; HEIGHT])]) -> Vec<(char, String)> {
+    font.iter()
+        .map(|(letter, rows)| {
+            let rows = rows
+                .iter()
+                .map(|row| row.chars().map(|c| c == '#').collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+            let lit = |x: &usize| rows.iter().any(|row| row[*x]);
+            let width = rows[0].len();
+            let start = (0..width).find(lit).unwrap();
+            let end = (0..width).rfind(lit).unwrap() + 1;
+            (*letter, draw(&rows, start..end))
+        })
+        .collect()
+}
+
+/// Some columns of the pixels as `#`s and `.`s, a line per row.
+fn draw(rows: &[Vec<bool>], columns: Range<usize>) -> String {
+    rows.iter()
+        .map(|row| {
+            columns
+                .clone()
+                .map(|x| if row.get(x) == Some(&true) { '#' } else { '.' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+mod test {
+
+    #[test]
+    fn reads_every_letter() {
+        use crate::ocr::{read, LARGE, SMALL};
+
+        /// Draws the letters the way the puzzles do, with `gap` blank columns after each.
+        fn render<const HEIGHT: usize>(
+            font: &[(char, [&str; HEIGHT])],
+            letters: &str,
+            gap: usize,
+        ) -> String {
+            (0..HEIGHT)
+                .map(|y| {
+                    letters
+                        .chars()
+                        .flat_map(|letter| {
+                            let (_, rows) = font.iter().find(|(c, _)| *c == letter).unwrap();
+                            [rows[y].to_string(), ".".repeat(gap)]
+                        })
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        }
+
+        let small = SMALL.iter().map(|(letter, _)| letter).collect::<String>();
+        assert_eq!(read(&render(SMALL, &small, 1)).unwrap(), small);
+        let large = LARGE.iter().map(|(letter, _)| letter).collect::<String>();
+        assert_eq!(read(&render(LARGE, &large, 2)).unwrap(), large);
+    }
+
+    #[test]
+    fn reads_screens() {
+        use grid::Grid;
+
+        use crate::{
+            input::Input,
+            ocr::{read, read_grid},
+        };
+
+        // Spaces for the unlit pixels, and blank rows around the letters.
+        let screen = "
+
+ ██  ███  █  █
+█  █ █  █ █  █
+█  █ ███  ████
+████ █  █ █  █
+█  █ █  █ █  █
+█  █ ███  █  █
+
+";
+        assert_eq!(read(screen).unwrap(), "ABH");
+
+        let pixels = Input::literal(
+            "
+            ######..#....#
+            #.......##...#
+            #.......##...#
+            #.......#.#..#
+            #####...#.#..#
+            #.......#..#.#
+            #.......#..#.#
+            #.......#...##
+            #.......#...##
+            #.......#....#
+            ",
+        );
+        let grid = Grid::parse(&pixels, |c| c == '#').unwrap();
+        assert_eq!(read_grid(&grid).unwrap(), "FN");
+    }
+
+    #[test]
+    fn says_what_it_cannot_read() {
+        use crate::ocr::{read, ReadError};
+
+        let screen = "
+.##..#...#.##.
+#..#.##.##....
+#..#.#.#.#.##.
+####.#...#...#
+#..#.#...#...#
+#..#.#...#.##.
+";
+        let error = read(screen).unwrap_err();
+        assert_eq!(
+            error,
+            ReadError::Unknown {
+                read: "A??".into(),
+                columns: vec![5..10, 11..14]
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            r#"read "A??", but didn't know the letters in columns 5-9, 11-13"#
+        );
+
+        assert_eq!(read("#..#\n####"), Err(ReadError::Height(2)));
+        assert_eq!(read(""), Err(ReadError::Height(0)));
+    }
+}
@@ -0,0 +1,411 @@
+//! Parsers for the `Thing {n}: {stuff}` lines most puzzles are made of.
+//!
+//! Rather than writing nom by hand for each of them, `#[derive(Parse)]` a struct with the line's
+//! format and it's parsed field by field, each field with its type's [`Parse`]:
+//!
+//! ```
+//! use aoc::parse::Parse;
+//!
+//! #[derive(Debug, PartialEq, Parse)]
+//! #[format("{min}-{max} {letter}: {password}")]
+//! struct Policy<'a> {
+//!     min: usize,
+//!     max: usize,
+//!     letter: char,
+//!     password: &'a str,
+//! }
+//!
+//! let (_, policy) = Policy::parse("1-3 a: abcde").unwrap();
+//! assert_eq!(policy, Policy { min: 1, max: 3, letter: 'a', password: "abcde" });
+//! ```
+//!
+//! Text in the format has to be there exactly, apart from the spaces numbers can have in front of
+//! them. A `&str` field takes everything up to the format's next bit of text, or the rest of the
+//! line if it's last. Other fields can be annotated with `#[format(...)]`:
+//!
+//! - `sep = "; "` makes the field one or more items with `; ` between them, collected into the
+//!   field with `Default` and `Extend`
+//! - `item = Type` is the type of those items, if it isn't the field type's type parameter, like
+//!   it is for a `Vec`
+//! - `recognize` keeps the text the items were in rather than collecting them, turned into the
+//!   field with `From<&str>`, for types that parse their items lazily without allocating
+//! - `with = function` parses the field, or its items, with a nom parser of our own instead
+//!
+//! Enums parse as whichever of their variants' formats matches first, each variant having its own
+//! `#[format("...")]`.
+//!
+//! [`parse_lines`] parses every line of an input, carrying on past the ones that don't parse so
+//! they can all be reported together, and either failing with them or skipping them.
+
+use std::{fmt, iter::Enumerate, marker::PhantomData, str::Lines};
+
+use nom::{
+    bytes::complete::{tag, take_while},
+    character::complete::{anychar, digit1, one_of},
+    combinator::{map_res, opt, recognize},
+    sequence::{pair, preceded},
+    IResult,
+};
+
+#[doc(hidden)]
+pub use nom;
+
+pub use aoc_macros::Parse;
+
+/// Something that can be parsed from the start of some text, leaving the rest.
+pub trait Parse<'a>: Sized {
+    fn parse(input: &'a str) -> IResult<&'a str, Self>;
+}
+
+fn spaces(input: &str) -> IResult<&str, &str> {
+    take_while(|c| c == ' ')(input)
+}
+
+macro_rules! unsigned {
+    ($($int:ty)*) => {
+        $(
+            /// Like `scanf`'s `%d`, spaces in front of the number are skipped, which is what lets
+            /// right-aligned columns of numbers parse.
+            impl<'a> Parse<'a> for $int {
+                fn parse(input: &'a str) -> IResult<&'a str, Self> {
+                    preceded(spaces, map_res(digit1, str::parse))(input)
+                }
+            }
+        )*
+    };
+}
+
+unsigned!(u8 u16 u32 u64 u128 usize);
+
+macro_rules! signed {
+    ($($int:ty)*) => {
+        $(
+            /// Like the unsigned numbers, with an optional sign.
+            impl<'a> Parse<'a> for $int {
+                fn parse(input: &'a str) -> IResult<&'a str, Self> {
+                    preceded(
+                        spaces,
+                        map_res(recognize(pair(opt(one_of("+-")), digit1)), str::parse),
+                    )(input)
+                }
+            }
+        )*
+    };
+}
+
+signed!(i8 i16 i32 i64 i128 isize);
+
+impl<'a> Parse<'a> for char {
+    fn parse(input: &'a str) -> IResult<&'a str, Self> {
+        anychar(input)
+    }
+}
+
+/// One or more `item`s with `sep` between them, collected into `C`, which is what a field with a
+/// `sep` parses with.
+pub fn separated<'a, T, C: Default + Extend<T>>(
+    mut item: impl FnMut(&'a str) -> IResult<&'a str, T>,
+    sep: &'static str,
+) -> impl FnMut(&'a str) -> IResult<&'a str, C> {
+    move |input| {
+        let mut collected = C::default();
+        let rest = items(&mut item, sep, input, |next| collected.extend([next]))?;
+        Ok((rest, collected))
+    }
+}
+
+/// The text one or more `item`s with `sep` between them were in, for fields that `recognize` them.
+pub fn recognize_separated<'a, T>(
+    mut item: impl FnMut(&'a str) -> IResult<&'a str, T>,
+    sep: &'static str,
+) -> impl FnMut(&'a str) -> IResult<&'a str, &'a str> {
+    move |input| {
+        let rest = items(&mut item, sep, input, drop)?;
+        Ok((rest, &input[..input.len() - rest.len()]))
+    }
+}
+
+/// Parses the items of a list, handing each to `each`, and returns what's left after them. The
+/// list stops at the first separator not followed by an item, which is left for what comes next.
+fn items<'a, T>(
+    item: &mut impl FnMut(&'a str) -> IResult<&'a str, T>,
+    sep: &'static str,
+    input: &'a str,
+    mut each: impl FnMut(T),
+) -> Result<&'a str, nom::Err<nom::error::Error<&'a str>>> {
+    let (mut input, first) = item(input)?;
+    each(first);
+    loop {
+        match preceded(tag(sep), &mut *item)(input) {
+            Ok((rest, next)) => {
+                each(next);
+                input = rest;
+            }
+            Err(nom::Err::Error(_)) => return Ok(input),
+            Err(error) => return Err(error),
+        }
+    }
+}
+
+/// What to do about lines that don't parse.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Mode {
+    /// Fail with every line that didn't parse, from [`ParseLines::collect_all`] or
+    /// [`ParseLines::finish`], for solutions that can't give an answer without all of them.
+    Strict,
+    /// Skip them, keeping them for [`ParseLines::errors`], for things like validation that want
+    /// to carry on with what did parse.
+    Lenient,
+}
+
+/// A line of input that didn't parse.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct LineError {
+    /// 1-based, like an editor shows it.
+    pub line: usize,
+    /// 1-based, where the parser gave up.
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for LineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {} column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+/// Every line of an input that didn't parse.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ParseErrors(pub Vec<LineError>);
+
+impl fmt::Display for ParseErrors {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let lines = if self.0.len() == 1 { "line" } else { "lines" };
+        write!(f, "{} {lines} of the input didn't parse", self.0.len())?;
+        for error in &self.0 {
+            write!(f, "\n{error}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ParseErrors {}
+
+/// Parses a whole line, so anything left over after the `T` is an error too.
+fn parse_line<'a, T: Parse<'a>>(line: usize, text: &'a str) -> Result<T, LineError> {
+    let column = |rest: &str| text.len() - rest.len() + 1;
+    match T::parse(text) {
+        Ok(("", parsed)) => Ok(parsed),
+        Ok((rest, _)) => Err(LineError {
+            line,
+            column: column(rest),
+            message: format!("`{rest}` is left over"),
+        }),
+        Err(nom::Err::Error(error) | nom::Err::Failure(error)) => Err(LineError {
+            line,
+            column: column(error.input),
+            message: match error.input {
+                "" => format!("`{text}` ends too soon"),
+                rest => format!("couldn't parse `{rest}`"),
+            },
+        }),
+        Err(nom::Err::Incomplete(_)) => Err(LineError {
+            line,
+            column: column(""),
+            message: format!("`{text}` ends too soon"),
+        }),
+    }
+}
+
+/// Parses each line of `input` as a `T`, carrying on past lines that don't parse rather than
+/// stopping at the first.
+///
+/// It's an iterator of the lines that did parse, with their line numbers, so solutions can stream
+/// through them without allocating. It only allocates for the lines that don't, which it skips
+/// in either [`Mode`], so a strict solution streaming through them wants to
+/// [`finish`](ParseLines::finish) once it's done.
+///
+/// ```
+/// use aoc::parse::{parse_lines, Mode};
+///
+/// let mut lines = parse_lines::<u32>("1\ntwo\n3\n4!", Mode::Lenient);
+/// assert_eq!(lines.by_ref().collect::<Vec<_>>(), [(1, 1), (3, 3)]);
+/// let errors = lines.errors().iter().map(|error| error.to_string()).collect::<Vec<_>>();
+/// assert_eq!(
+///     errors,
+///     [
+///         "line 2 column 1: couldn't parse `two`",
+///         "line 4 column 2: `!` is left over",
+///     ]
+/// );
+/// ```
+pub fn parse_lines<'a, T: Parse<'a>>(input: &'a str, mode: Mode) -> ParseLines<'a, T> {
+    ParseLines {
+        lines: input.lines().enumerate(),
+        mode,
+        errors: vec![],
+        parsed: PhantomData,
+    }
+}
+
+/// The iterator [`parse_lines`] returns.
+pub struct ParseLines<'a, T> {
+    lines: Enumerate<Lines<'a>>,
+    mode: Mode,
+    errors: Vec<LineError>,
+    parsed: PhantomData<fn() -> T>,
+}
+
+impl<T> ParseLines<'_, T> {
+    /// The lines that didn't parse so far.
+    pub fn errors(&self) -> &[LineError] {
+        &self.errors
+    }
+}
+
+impl<'a, T: Parse<'a>> ParseLines<'a, T> {
+    /// Parses the rest of the lines, giving back what parsed, or in [`Mode::Strict`] every line
+    /// that didn't if any didn't.
+    pub fn collect_all(mut self) -> Result<Vec<(usize, T)>, ParseErrors> {
+        let parsed = self.by_ref().collect();
+        self.finish().map(|()| parsed)
+    }
+
+    /// Parses the rest of the lines, failing in [`Mode::Strict`] with every line that didn't
+    /// parse if any didn't, for solutions that stream through the lines rather than collecting
+    /// them.
+    ///
+    /// ```
+    /// use aoc::parse::{parse_lines, Mode};
+    ///
+    /// let mut lines = parse_lines::<u32>("1\ntwo\n3", Mode::Strict);
+    /// let total = lines.by_ref().map(|(_, number)| number).sum::<u32>();
+    /// assert_eq!(total, 4);
+    /// assert_eq!(lines.finish().unwrap_err().0[0].line, 2);
+    /// ```
+    pub fn finish(mut self) -> Result<(), ParseErrors> {
+        self.by_ref().for_each(drop);
+        match self.mode {
+            Mode::Strict if !self.errors.is_empty() => Err(ParseErrors(self.errors)),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<'a, T: Parse<'a>> Iterator for ParseLines<'a, T> {
+    type Item = (usize, T);
+
+    fn next(&mut self) -> Option<(usize, T)> {
+        for (index, text) in self.lines.by_ref() {
+            match parse_line(index + 1, text) {
+                Ok(parsed) => return Some((index + 1, parsed)),
+                Err(error) => self.errors.push(error),
+            }
+        }
+        None
+    }
+}
+
+mod test {
+
+    #[test]
+    fn numbers() {
+        use crate::parse::Parse;
+
+        assert_eq!(usize::parse("42 rest"), Ok((" rest", 42)));
+        assert_eq!(u8::parse("   7|"), Ok(("|", 7)));
+        assert!(u8::parse("256").is_err());
+        assert!(u32::parse("-1").is_err());
+        assert_eq!(i64::parse(" -12,"), Ok((",", -12)));
+        assert_eq!(i64::parse("+3"), Ok(("", 3)));
+        assert_eq!(char::parse("ab"), Ok(("b", 'a')));
+    }
+
+    #[test]
+    fn separated() {
+        use crate::parse::{recognize_separated, separated, Parse};
+
+        let mut numbers = separated::<_, Vec<u32>>(u32::parse, ", ");
+        assert_eq!(numbers("1, 2,  3, x"), Ok((", x", vec![1, 2, 3])));
+        assert!(numbers("x").is_err());
+
+        let mut numbers = recognize_separated(u32::parse, "; ");
+        assert_eq!(numbers("1; 2; 3 end"), Ok((" end", "1; 2; 3")));
+    }
+
+    #[test]
+    fn strict_lines_fail_with_every_bad_line() {
+        use crate::parse::{parse_lines, Mode};
+
+        let input = "1\ntwo\n3\n4x\n";
+        let errors = parse_lines::<u32>(input, Mode::Strict).collect_all();
+        insta::assert_snapshot!(errors.unwrap_err().to_string(), @"
+        2 lines of the input didn't parse
+        line 2 column 1: couldn't parse `two`
+        line 4 column 2: `x` is left over
+        ");
+        let lenient = parse_lines::<u32>("1\n2\nx", Mode::Lenient).collect_all();
+        assert_eq!(lenient, Ok(vec![(1, 1), (2, 2)]));
+
+        let mut strict = parse_lines::<u32>("x\n1\ny", Mode::Strict);
+        assert_eq!(strict.by_ref().count(), 1);
+        assert_eq!(strict.finish().unwrap_err().0.len(), 2);
+        assert_eq!(parse_lines::<u32>("x", Mode::Lenient).finish(), Ok(()));
+    }
+
+    #[test]
+    fn derives_from_formats() {
+        use crate::parse::Parse;
+
+        #[derive(Debug, PartialEq, Parse)]
+        enum Instruction {
+            #[format("noop")]
+            Noop,
+            #[format("addx {0}")]
+            Add(i32),
+            #[format("move {count} from {from} to {to}")]
+            Move { count: usize, from: u8, to: u8 },
+        }
+
+        assert_eq!(Instruction::parse("noop"), Ok(("", Instruction::Noop)));
+        assert_eq!(
+            Instruction::parse("addx -3"),
+            Ok(("", Instruction::Add(-3)))
+        );
+        assert_eq!(
+            Instruction::parse("move 3 from 1 to 2"),
+            Ok((
+                "",
+                Instruction::Move {
+                    count: 3,
+                    from: 1,
+                    to: 2
+                }
+            ))
+        );
+        assert!(Instruction::parse("jump 3").is_err());
+
+        #[derive(Debug, PartialEq, Parse)]
+        #[format("{{{name}}} -> {targets}")]
+        struct Module<'a> {
+            name: &'a str,
+            #[format(sep = ", ")]
+            targets: Vec<char>,
+        }
+
+        assert_eq!(
+            Module::parse("{broadcaster} -> a, b, c"),
+            Ok((
+                "",
+                Module {
+                    name: "broadcaster",
+                    targets: vec!['a', 'b', 'c']
+                }
+            ))
+        );
+    }
+}
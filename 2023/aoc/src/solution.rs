@@ -0,0 +1,238 @@
+use std::{any::Any, collections::BTreeMap, fmt, path::PathBuf};
+
+use crate::{
+    answer::{Answer, Overflow},
+    explain::Explanation,
+    input::Input,
+    parse::ParseErrors,
+};
+
+#[doc(hidden)]
+pub use inventory;
+
+/// A part of a day we have a solution for, and how to run it.
+///
+/// These are registered with `#[aoc(year, day, part)]` on the part's function, and collected from
+/// every crate linked in when the program starts, so nothing has to keep a list of them by hand.
+pub struct Solution {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// The cargo package the solution lives in.
+    pub package: &'static str,
+    /// The directory of the crate the solution lives in.
+    pub crate_dir: &'static str,
+    /// Where the puzzle input lives, relative to `crate_dir`.
+    pub input: &'static str,
+    pub solve: Solve,
+    pub explain: Option<fn(&str) -> Explanation>,
+}
+
+/// What a part's function takes.
+pub enum Solve {
+    /// The puzzle input.
+    Input(fn(&str) -> Result<Answer, SolveError>),
+    /// What the day's `#[aoc_generator]` made of the puzzle input.
+    Generated(fn(&dyn Any) -> Result<Answer, SolveError>),
+}
+
+/// Why a part didn't give an answer.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum SolveError {
+    Overflow(Overflow),
+    /// Lines of the input that didn't parse.
+    Parse(ParseErrors),
+}
+
+impl From<Overflow> for SolveError {
+    fn from(overflow: Overflow) -> Self {
+        SolveError::Overflow(overflow)
+    }
+}
+
+impl From<ParseErrors> for SolveError {
+    fn from(errors: ParseErrors) -> Self {
+        SolveError::Parse(errors)
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Overflow(overflow) => write!(f, "the answer overflowed: {overflow}"),
+            SolveError::Parse(errors) => write!(f, "{errors}"),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+/// Parses a day's input into what both its parts take, registered with
+/// `#[aoc_generator(year, day)]`.
+pub struct Generator {
+    pub year: u16,
+    pub day: u8,
+    pub generate: fn(&str) -> Box<dyn Any>,
+}
+
+inventory::collect!(Solution);
+inventory::collect!(Generator);
+
+impl Solution {
+    pub fn solve(&self, input: &str) -> Result<Answer, SolveError> {
+        match self.solve {
+            Solve::Input(solve) => solve(input),
+            Solve::Generated(solve) => solve(&*self.generate(input)),
+        }
+    }
+
+    /// Solves the part with what its day's generator already made of the input.
+    fn solve_generated(&self, input: &str, generated: &dyn Any) -> Result<Answer, SolveError> {
+        match self.solve {
+            Solve::Input(solve) => solve(input),
+            Solve::Generated(solve) => solve(generated),
+        }
+    }
+
+    fn generate(&self, input: &str) -> Box<dyn Any> {
+        let generator = generator(self.year, self.day).unwrap_or_else(|| {
+            panic!(
+                "{} day {} part {} takes generated input, but there's no #[aoc_generator] for it",
+                self.year, self.day, self.part
+            )
+        });
+        (generator.generate)(input)
+    }
+
+    pub fn input_path(&self) -> PathBuf {
+        PathBuf::from(self.crate_dir).join(self.input)
+    }
+
+    /// What the tests for this part have in their paths, `part_one` or `part_two`.
+    ///
+    /// Cargo matches it anywhere in a test's path, so a test in one part's module mustn't name the
+    /// other part.
+    pub fn test_filter(&self) -> &'static str {
+        match self.part {
+            1 => "part_one",
+            2 => "part_two",
+            part => unreachable!("there's no part {part}"),
+        }
+    }
+}
+
+/// Sorts solutions into order, checking no part has two of them.
+fn in_order(mut solutions: Vec<&'static Solution>) -> Vec<&'static Solution> {
+    solutions.sort_by_key(|solution| (solution.year, solution.day, solution.part));
+    for pair in solutions.windows(2) {
+        let [first, second] = [pair[0], pair[1]];
+        if (first.year, first.day, first.part) == (second.year, second.day, second.part) {
+            panic!(
+                "{} day {} part {} is registered by both {} and {}",
+                first.year, first.day, first.part, first.package, second.package
+            );
+        }
+    }
+    solutions
+}
+
+/// Every registered solution, in order.
+///
+/// # Panics
+///
+/// If a part is registered more than once, as there'd be no telling which one we'd run.
+pub fn solutions() -> Vec<&'static Solution> {
+    in_order(inventory::iter::<Solution>.into_iter().collect())
+}
+
+pub fn find(year: u16, day: u8, part: u8) -> Option<&'static Solution> {
+    solutions()
+        .into_iter()
+        .find(|solution| (solution.year, solution.day, solution.part) == (year, day, part))
+}
+
+fn generator(year: u16, day: u8) -> Option<&'static Generator> {
+    inventory::iter::<Generator>
+        .into_iter()
+        .find(|generator| (generator.year, generator.day) == (year, day))
+}
+
+/// Solves both of a day's parts, running its generator just the once if they share one.
+pub fn solve_day(year: u16, day: u8, input: &str) -> Vec<(u8, Result<Answer, SolveError>)> {
+    let parts = solutions()
+        .into_iter()
+        .filter(|solution| (solution.year, solution.day) == (year, day))
+        .collect::<Vec<_>>();
+    let needs_generator = parts
+        .iter()
+        .any(|solution| matches!(solution.solve, Solve::Generated(_)));
+    let generated: Box<dyn Any> = match parts.first() {
+        Some(solution) if needs_generator => solution.generate(input),
+        _ => Box::new(()),
+    };
+    parts
+        .into_iter()
+        .map(|solution| (solution.part, solution.solve_generated(input, &*generated)))
+        .collect()
+}
+
+/// Prints the answers of every part in `package` for its puzzle input, which is what
+/// [`main!`](crate::main) does.
+pub fn print_answers(package: &str) {
+    let mut days = BTreeMap::<_, &Solution>::new();
+    for solution in solutions() {
+        if solution.package == package {
+            days.entry((solution.year, solution.day))
+                .or_insert(solution);
+        }
+    }
+    if days.is_empty() {
+        println!("{package} has no #[aoc] solutions");
+    }
+    for ((year, day), solution) in days {
+        let path = solution.input_path();
+        let input = match Input::read(&path) {
+            Ok(input) => input,
+            Err(error) => {
+                println!(
+                    "{year} day {day}: couldn't read {}: {error}",
+                    path.display()
+                );
+                continue;
+            }
+        };
+        for (part, answer) in solve_day(year, day, &input) {
+            match answer {
+                Ok(answer) => println!("{year} day {day} part {part}: {answer}"),
+                Err(error) => println!("{year} day {day} part {part}: {error}"),
+            }
+        }
+    }
+}
+
+mod test {
+
+    #[test]
+    #[should_panic(expected = "2023 day 4 part 2 is registered by both day-4 and day-four")]
+    fn parts_are_registered_once() {
+        use crate::{
+            answer::Answer,
+            solution::{in_order, Solution, Solve},
+        };
+
+        fn solution(package: &'static str) -> &'static Solution {
+            Box::leak(Box::new(Solution {
+                year: 2023,
+                day: 4,
+                part: 2,
+                package,
+                crate_dir: "",
+                input: "",
+                solve: Solve::Input(|_| Ok(Answer::ZERO)),
+                explain: None,
+            }))
+        }
+
+        in_order(vec![solution("day-4"), solution("day-four")]);
+    }
+}
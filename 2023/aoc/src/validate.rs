@@ -0,0 +1,208 @@
+//! Checks of a day's input for things its parser lets through but the puzzle doesn't expect, like
+//! a card missing from the pile or a hand naming a color twice.
+//!
+//! The parsers only care whether each line has the right shape, and the solutions assume the rest,
+//! so a mistyped or hand-edited input gives a wrong answer rather than an error. A day registers a
+//! check of its input with `#[aoc_validator(year, day)]`, and the runner's `validate` runs it.
+//! Lines that don't parse at all are errors, everything else it finds is a warning, as the
+//! solutions may well still get the right answer.
+
+use std::{collections::HashMap, fmt};
+
+use crate::parse::LineError;
+
+/// Something wrong with a line of the input.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Issue {
+    /// 1-based, like an editor shows it.
+    pub line: usize,
+    pub message: String,
+}
+
+/// What a day's validator found wrong with its input.
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct Report {
+    /// Lines that don't parse.
+    pub errors: Vec<Issue>,
+    /// Lines that parse, but aren't what the puzzle says they'll be.
+    pub warnings: Vec<Issue>,
+}
+
+impl Report {
+    pub fn error(&mut self, line: usize, message: impl Into<String>) {
+        self.errors.push(Issue {
+            line,
+            message: message.into(),
+        });
+    }
+
+    pub fn warn(&mut self, line: usize, message: impl Into<String>) {
+        self.warnings.push(Issue {
+            line,
+            message: message.into(),
+        });
+    }
+
+    /// Adds the lines that didn't parse, from [`parse_lines`](crate::parse::parse_lines) in
+    /// [`Mode::Lenient`](crate::parse::Mode::Lenient).
+    pub fn parse_errors(&mut self, errors: &[LineError]) {
+        for error in errors {
+            self.error(
+                error.line,
+                format!("column {}: {}", error.column, error.message),
+            );
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty() && self.warnings.is_empty()
+    }
+
+    /// Warns about `what`s that aren't numbered 1, 2, 3 and so on, given each one's line and
+    /// number: duplicates, ones out of order, and gaps.
+    ///
+    /// A number only counts as missing if it's nowhere at all, so one that's just out of order
+    /// isn't reported twice. Gaps are reported on the line of the number after them.
+    ///
+    /// ```
+    /// use aoc::validate::Report;
+    /// let mut report = Report::default();
+    /// report.check_numbering("card", [(1, 1), (2, 2), (3, 6), (4, 6), (5, 4), (6, 7)]);
+    /// let warnings = report.warnings.iter().map(|issue| issue.to_string()).collect::<Vec<_>>();
+    /// assert_eq!(
+    ///     warnings,
+    ///     [
+    ///         "line 4: card 6 is already on line 3",
+    ///         "line 5: card 4 comes after card 6",
+    ///         "line 5: card 3 is missing",
+    ///         "line 3: card 5 is missing",
+    ///     ]
+    /// );
+    /// ```
+    pub fn check_numbering(
+        &mut self,
+        what: &str,
+        numbers: impl IntoIterator<Item = (usize, usize)>,
+    ) {
+        let mut seen = HashMap::new();
+        let mut highest = 0;
+        for (line, number) in numbers {
+            if let Some(&first) = seen.get(&number) {
+                self.warn(line, format!("{what} {number} is already on line {first}"));
+                continue;
+            }
+            seen.insert(number, line);
+            if number == 0 {
+                self.warn(line, format!("{what}s are numbered from 1, not 0"));
+            } else if number < highest {
+                self.warn(
+                    line,
+                    format!("{what} {number} comes after {what} {highest}"),
+                );
+            }
+            highest = highest.max(number);
+        }
+
+        let mut seen = seen.into_iter().collect::<Vec<_>>();
+        seen.sort();
+        let mut next = 1;
+        for (number, line) in seen {
+            if number == next + 1 {
+                self.warn(line, format!("{what} {next} is missing"));
+            } else if number > next {
+                self.warn(
+                    line,
+                    format!("{what}s {next} to {} are missing", number - 1),
+                );
+            }
+            next = next.max(number.saturating_add(1));
+        }
+    }
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return f.write_str("nothing wrong with the input");
+        }
+        let errors = self.errors.iter().map(|issue| ("error", issue));
+        let warnings = self.warnings.iter().map(|issue| ("warning", issue));
+        for (index, (kind, issue)) in errors.chain(warnings).enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{kind}: {issue}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Checks a day's input, registered with `#[aoc_validator(year, day)]`.
+pub struct Validator {
+    pub year: u16,
+    pub day: u8,
+    pub validate: fn(&str) -> Report,
+}
+
+crate::solution::inventory::collect!(Validator);
+
+/// Every registered validator's day, in order.
+pub fn validated_days() -> Vec<(u16, u8)> {
+    let mut days = crate::solution::inventory::iter::<Validator>
+        .into_iter()
+        .map(|validator| (validator.year, validator.day))
+        .collect::<Vec<_>>();
+    days.sort();
+    days
+}
+
+pub fn find(year: u16, day: u8) -> Option<&'static Validator> {
+    crate::solution::inventory::iter::<Validator>
+        .into_iter()
+        .find(|validator| (validator.year, validator.day) == (year, day))
+}
+
+mod test {
+
+    #[test]
+    fn numbering_in_order_is_fine() {
+        use crate::validate::Report;
+
+        let mut report = Report::default();
+        report.check_numbering("game", (1..=100).map(|number| (number, number)));
+        assert!(report.is_empty());
+    }
+
+    #[test]
+    fn numbering_goes_up_to_the_biggest_number() {
+        use crate::validate::Report;
+
+        let mut report = Report::default();
+        report.check_numbering("game", [(1, 1), (2, usize::MAX), (3, 2)]);
+        insta::assert_snapshot!(report.to_string(), @"
+        warning: line 3: game 2 comes after game 18446744073709551615
+        warning: line 2: games 3 to 18446744073709551614 are missing
+        ");
+    }
+
+    #[test]
+    fn errors_come_before_warnings() {
+        use crate::validate::Report;
+
+        let mut report = Report::default();
+        assert_eq!(report.to_string(), "nothing wrong with the input");
+        report.check_numbering("game", [(1, 0), (2, 2)]);
+        report.error(3, "`Game 3` isn't a game");
+        insta::assert_snapshot!(report.to_string(), @"
+        error: line 3: `Game 3` isn't a game
+        warning: line 1: games are numbered from 1, not 0
+        warning: line 2: game 1 is missing
+        ");
+    }
+}
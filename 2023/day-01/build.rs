@@ -0,0 +1,4 @@
+fn main() {
+    // Pick up new examples for `aoc_macros::example_tests!`.
+    println!("cargo:rerun-if-changed=examples");
+}
@@ -0,0 +1,75 @@
+/// Which characters count as digits on a line.
+///
+/// `char::is_numeric` is no good for this: it takes things like `½` and `Ⅻ` that aren't digits,
+/// and the digits it does take from other scripts, like `٣`, don't parse as numbers.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum DigitPolicy {
+    /// Only `0` to `9`, which is all the puzzle has.
+    #[default]
+    Ascii,
+    /// Any of Unicode's decimal digits, like `٣` or `३`, for the digit they write. Other numeric
+    /// characters, like `½`, still aren't digits.
+    UnicodeDecimal,
+}
+
+impl DigitPolicy {
+    /// What `c` is worth as a digit, or `None` if it isn't one.
+    ///
+    /// ```
+    /// use day_one::digit::DigitPolicy;
+    /// assert_eq!(DigitPolicy::Ascii.value('7'), Some(7));
+    /// assert_eq!(DigitPolicy::Ascii.value('٣'), None);
+    /// assert_eq!(DigitPolicy::UnicodeDecimal.value('٣'), Some(3));
+    /// assert_eq!(DigitPolicy::UnicodeDecimal.value('½'), None);
+    /// ```
+    pub fn value(self, c: char) -> Option<u32> {
+        match self {
+            DigitPolicy::Ascii => c.to_digit(10),
+            DigitPolicy::UnicodeDecimal => {
+                let c = u32::from(c);
+                DECIMAL_ZEROS
+                    .iter()
+                    .find(|&&zero| (zero..zero + 10).contains(&c))
+                    .map(|zero| c - zero)
+            }
+        }
+    }
+}
+
+/// Unicode's decimal digits (general category `Nd`) always come in runs of ten, `0` to `9`, so
+/// knowing where each run's zero is tells us every digit's value. As of Unicode 15.
+const DECIMAL_ZEROS: &[u32] = &[
+    0x0030, 0x0660, 0x06F0, 0x07C0, 0x0966, 0x09E6, 0x0A66, 0x0AE6, 0x0B66, 0x0BE6, 0x0C66, 0x0CE6,
+    0x0D66, 0x0DE6, 0x0E50, 0x0ED0, 0x0F20, 0x1040, 0x1090, 0x17E0, 0x1810, 0x1946, 0x19D0, 0x1A80,
+    0x1A90, 0x1B50, 0x1BB0, 0x1C40, 0x1C50, 0xA620, 0xA8D0, 0xA900, 0xA9D0, 0xA9F0, 0xAA50, 0xABF0,
+    0xFF10, 0x104A0, 0x10D30, 0x11066, 0x110F0, 0x11136, 0x111D0, 0x112F0, 0x11450, 0x114D0,
+    0x11650, 0x116C0, 0x11730, 0x118E0, 0x11950, 0x11C50, 0x11D50, 0x11DA0, 0x11F50, 0x16A60,
+    0x16AC0, 0x16B50, 0x1D7CE, 0x1D7D8, 0x1D7E2, 0x1D7EC, 0x1D7F6, 0x1E140, 0x1E2F0, 0x1E4F0,
+    0x1E950, 0x1FBF0,
+];
+
+mod test {
+
+    #[test]
+    fn decimal_digits_from_every_script() {
+        use crate::digit::{DigitPolicy, DECIMAL_ZEROS};
+
+        for &zero in DECIMAL_ZEROS {
+            for value in 0..10 {
+                let c = char::from_u32(zero + value).unwrap();
+                assert!(c.is_numeric(), "{c:?}");
+                assert_eq!(DigitPolicy::UnicodeDecimal.value(c), Some(value), "{c:?}");
+            }
+        }
+        // Devanagari, fullwidth and mathematical bold.
+        for c in ['३', '３', '𝟑'] {
+            assert_eq!(DigitPolicy::UnicodeDecimal.value(c), Some(3), "{c:?}");
+            assert_eq!(DigitPolicy::Ascii.value(c), None, "{c:?}");
+        }
+        // Numeric, but not decimal digits.
+        for c in ['½', 'Ⅻ', '²', '①'] {
+            assert!(c.is_numeric(), "{c:?}");
+            assert_eq!(DigitPolicy::UnicodeDecimal.value(c), None, "{c:?}");
+        }
+    }
+}
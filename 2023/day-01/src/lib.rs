@@ -0,0 +1,287 @@
+use std::usize;
+
+use aoc::{
+    answer::{Accumulate, Answer, Overflow},
+    explain::Explanation,
+};
+use aoc_macros::aoc;
+
+pub mod digit;
+
+use digit::DigitPolicy;
+
+/// A digit we found on a line, and the word it was spelled out as if it wasn't numeric.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Digit<'a> {
+    pub value: u32,
+    pub word: Option<&'a str>,
+}
+
+impl<'a> Digit<'a> {
+    fn numeric(value: u32) -> Self {
+        Self { value, word: None }
+    }
+
+    fn word(word: &'a str) -> Self {
+        Self {
+            value: numstring_tostring(word).parse().unwrap(),
+            word: Some(word),
+        }
+    }
+}
+
+/// The first and last digit of a line, which make up its calibration value.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Calibration<'a> {
+    pub first: Digit<'a>,
+    pub last: Digit<'a>,
+}
+
+impl Calibration<'_> {
+    pub fn value(&self) -> usize {
+        (self.first.value * 10 + self.last.value) as usize
+    }
+
+    fn explain(self, explanation: &mut Explanation, line: usize) {
+        let words = [self.first.word, self.last.word]
+            .iter()
+            .flatten()
+            .copied()
+            .collect::<Vec<_>>();
+        explanation.push(
+            line,
+            vec![
+                self.first.value.to_string(),
+                self.last.value.to_string(),
+                words.join(", "),
+            ],
+            self.value(),
+        );
+    }
+}
+
+const EXPLANATION_COLUMNS: [&str; 3] = ["first", "last", "word"];
+
+/// The first digit on the line, with the byte it starts at, like `str::find` would give.
+fn first_numeric(line: &str, policy: DigitPolicy) -> Option<(usize, u32)> {
+    line.char_indices()
+        .find_map(|(index, c)| Some((index, policy.value(c)?)))
+}
+
+fn last_numeric(line: &str, policy: DigitPolicy) -> Option<(usize, u32)> {
+    line.char_indices()
+        .rev()
+        .find_map(|(index, c)| Some((index, policy.value(c)?)))
+}
+
+fn calibration_part_one(line: &str, policy: DigitPolicy) -> Calibration {
+    let no_digits = || panic!("no digits in {line:?}");
+    let (_, first) = first_numeric(line, policy).unwrap_or_else(no_digits);
+    let (_, last) = last_numeric(line, policy).unwrap_or_else(no_digits);
+    Calibration {
+        first: Digit::numeric(first),
+        last: Digit::numeric(last),
+    }
+}
+
+#[aoc(2023, 1, 1, explain = explain_part_one)]
+pub fn part_one(input: &str) -> Result<Answer, Overflow> {
+    part_one_with(DigitPolicy::default(), input)
+}
+
+/// Like [`part_one`], with `policy` saying what counts as a digit.
+///
+/// ```
+/// use day_one::{digit::DigitPolicy, part_one_with};
+/// assert_eq!(part_one_with(DigitPolicy::Ascii, "½x٣y7z").unwrap(), 77usize);
+/// assert_eq!(part_one_with(DigitPolicy::UnicodeDecimal, "½x٣y7z").unwrap(), 37usize);
+/// ```
+pub fn part_one_with(policy: DigitPolicy, input: &str) -> Result<Answer, Overflow> {
+    input
+        .split('\n')
+        .map(|line| calibration_part_one(line, policy).value())
+        .sum_answers()
+}
+
+/// Which digit each line's calibration value came from.
+pub fn explain_part_one(input: &str) -> Explanation {
+    let mut explanation = Explanation::new(&EXPLANATION_COLUMNS);
+    for (index, line) in input.split('\n').enumerate() {
+        calibration_part_one(line, DigitPolicy::default()).explain(&mut explanation, index + 1);
+    }
+    explanation
+}
+
+fn find_alpha_nums(input: &str) -> ((usize, &str), (usize, &str)) {
+    let nums = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "zero",
+    ];
+
+    let x = nums.iter().flat_map(|num| input.match_indices(num));
+
+    let first = x.clone().fold((usize::MAX, ""), |best_index, curr_index| {
+        if curr_index.0 < best_index.0 {
+            curr_index
+        } else {
+            best_index
+        }
+    });
+
+    let last = x.fold((usize::MIN, ""), |best_index, curr_index| {
+        if curr_index.0 > best_index.0 {
+            curr_index
+        } else {
+            best_index
+        }
+    });
+
+    (first, last)
+}
+
+fn numstring_tostring(input: &str) -> &str {
+    match input {
+        "one" => "1",
+        "two" => "2",
+        "three" => "3",
+        "four" => "4",
+        "five" => "5",
+        "six" => "6",
+        "seven" => "7",
+        "eight" => "8",
+        "nine" => "9",
+        "zero" => "0",
+        _ => unreachable!("pls don't gib other options :3, ${:?}", input),
+    }
+}
+
+fn calibration_part_two(line: &str, policy: DigitPolicy) -> Calibration {
+    let ((first_word_index, first_word), (last_word_index, last_word)) = find_alpha_nums(line);
+    // Both the numeric digits' and the words' indices are byte offsets, so they compare.
+    let first = match first_numeric(line, policy) {
+        Some((index, value)) if first_word.is_empty() || index < first_word_index => {
+            Digit::numeric(value)
+        }
+        _ if !first_word.is_empty() => Digit::word(first_word),
+        _ => panic!("no digits in {line:?}"),
+    };
+    let last = match last_numeric(line, policy) {
+        Some((index, value)) if last_word.is_empty() || index > last_word_index => {
+            Digit::numeric(value)
+        }
+        _ if !last_word.is_empty() => Digit::word(last_word),
+        _ => panic!("no digits in {line:?}"),
+    };
+    Calibration { first, last }
+}
+
+#[aoc(2023, 1, 2, explain = explain_part_two)]
+pub fn part_two(input: &str) -> Result<Answer, Overflow> {
+    part_two_with(DigitPolicy::default(), input)
+}
+
+/// Like [`part_two`], with `policy` saying what counts as a numeric digit.
+pub fn part_two_with(policy: DigitPolicy, input: &str) -> Result<Answer, Overflow> {
+    input
+        .split('\n')
+        .map(|line| calibration_part_two(line, policy).value())
+        .sum_answers()
+}
+
+/// Which digit, or spelled out word, each line's calibration value came from.
+pub fn explain_part_two(input: &str) -> Explanation {
+    let mut explanation = Explanation::new(&EXPLANATION_COLUMNS);
+    for (index, line) in input.split('\n').enumerate() {
+        calibration_part_two(line, DigitPolicy::default()).explain(&mut explanation, index + 1);
+    }
+    explanation
+}
+
+aoc_macros::example_tests! {
+    part_one: crate::part_one,
+    part_two: crate::part_two,
+}
+
+#[cfg(test)]
+mod tests {
+
+    #[test]
+    fn test_example_part_one() {
+        use crate::part_one;
+        let input = aoc::input!();
+        assert_eq!(part_one(&input).unwrap(), 54630usize);
+    }
+
+    #[test]
+    fn test_example_part_two() {
+        use crate::part_two;
+        let input = aoc::input!();
+        assert_eq!(part_two(&input).unwrap(), 54770usize);
+    }
+
+    #[test]
+    fn test_find_alpha_nums() {
+        use crate::find_alpha_nums;
+        let input = "onetwothree";
+        insta::assert_debug_snapshot!(find_alpha_nums(input), @r###"
+        (
+            (
+                0,
+                "one",
+            ),
+            (
+                6,
+                "three",
+            ),
+        )
+        "###);
+    }
+
+    #[test]
+    fn explain_provided_example_part_two() {
+        use crate::explain_part_two;
+        let input = include_str!("../examples/2.txt");
+        let explanation = explain_part_two(input);
+        assert_eq!(explanation.total().unwrap(), 281);
+        insta::assert_snapshot!(explanation.lines(3..=5).to_string(), @"
+        line │ first │ last │ word       │ contribution
+        ─────┼───────┼──────┼────────────┼─────────────
+        3    │ 1     │ 3    │ one, three │ 13
+        4    │ 2     │ 4    │ two, four  │ 24
+        5    │ 4     │ 2    │            │ 42
+        total: 79
+        ");
+    }
+
+    #[test]
+    fn multilingual_lines() {
+        use crate::{digit::DigitPolicy, part_one_with, part_two_with};
+
+        // Multi-byte characters ahead of the digits, so byte and char indices disagree.
+        let input = "é1ünf2ß\nstraße7\n日本eight語2x";
+        assert_eq!(
+            part_one_with(DigitPolicy::Ascii, input).unwrap(),
+            12usize + 77 + 22
+        );
+        assert_eq!(
+            part_two_with(DigitPolicy::Ascii, input).unwrap(),
+            12usize + 77 + 82
+        );
+
+        // Arabic-Indic, Devanagari and fullwidth digits, next to numerals that aren't digits.
+        let input = "٣½x٧\nⅫ३two\nnine８";
+        assert_eq!(
+            part_one_with(DigitPolicy::UnicodeDecimal, input).unwrap(),
+            37usize + 33 + 88
+        );
+        assert_eq!(
+            part_two_with(DigitPolicy::UnicodeDecimal, input).unwrap(),
+            37usize + 32 + 98
+        );
+    }
+
+    #[test]
+    #[should_panic = "no digits in \"٣½x٧\""]
+    fn ascii_only_by_default() {
+        crate::part_one("1x\n٣½x٧").unwrap();
+    }
+}
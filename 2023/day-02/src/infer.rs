@@ -0,0 +1,307 @@
+//! Working out what's likely to be in the bag from the hands the elf drew out of it, rather than
+//! just the smallest bag it could be, like part two does.
+//!
+//! Each hand is a handful of cubes drawn from the bag at once, so without replacement, and put back
+//! before the next. So for a bag of `N` cubes, a hand of `n` of them with `x` red, `y` green and
+//! `z` blue comes up with probability `C(red, x) C(green, y) C(blue, z) / C(N, n)`, and a game's
+//! hands together with the product of theirs. How many cubes the elf grabbed is up to the elf, so
+//! that's taken as given. The bag that makes the hands we saw most likely is our best guess at
+//! what's in it, and [`validate`] checks how good a guess that is by drawing games from bags we
+//! know.
+//!
+//! It's a better guess the more hands there are to go on. With only a few, it tends to guess bags
+//! bigger than the real one, and isn't much closer than the min bag. A bag of 12 cubes with 20
+//! hands of 6 comes out a cube or two out either way, but with 200 hands it's nearly always spot on.
+
+use rand::{seq::SliceRandom, Rng};
+
+use crate::{Bag, Color, Game};
+
+/// `ln(n!)` up to some `n`, since the binomials get too big for anything but their logs.
+struct LnFactorials(Vec<f64>);
+
+impl LnFactorials {
+    fn up_to(max: usize) -> Self {
+        let mut ln = vec![0.0; max + 1];
+        for n in 1..=max {
+            ln[n] = ln[n - 1] + (n as f64).ln();
+        }
+        Self(ln)
+    }
+
+    /// `ln(C(n, k))`, or negative infinity if there's no way to choose `k` of `n`.
+    fn ln_choose(&self, n: usize, k: usize) -> f64 {
+        if k > n {
+            return f64::NEG_INFINITY;
+        }
+        self.0[n] - self.0[k] - self.0[n - k]
+    }
+
+    fn ln_likelihood(&self, bag: &Bag, hands: &[Bag]) -> f64 {
+        hands
+            .iter()
+            .map(|hand| {
+                let colors = Color::ALL
+                    .iter()
+                    .map(|&color| self.ln_choose(bag[color], hand[color]))
+                    .sum::<f64>();
+                // A hand with more cubes than the bag would otherwise be `-inf - -inf`, which is NaN.
+                if colors == f64::NEG_INFINITY {
+                    return f64::NEG_INFINITY;
+                }
+                colors - self.ln_choose(bag.cubes(), hand.cubes())
+            })
+            .sum()
+    }
+}
+
+/// The counts of each color in each of the game's hands.
+fn hands(game: &Game) -> Vec<Bag> {
+    game.hands.iter().map(|hand| hand.min_bag()).collect()
+}
+
+/// The log of how likely the game's hands are to be drawn from `bag`, negative infinity if they
+/// can't be.
+///
+/// ```
+/// use aoc::parse::Parse;
+/// use day_two::{infer::log_likelihood, Bag, Game};
+///
+/// let (_, game) = Game::parse("Game 1: 1 red; 1 blue").unwrap();
+/// // Each hand is one of the bag's two cubes.
+/// assert_eq!(log_likelihood(&Bag::new(1, 0, 1), &game), (0.5f64 * 0.5).ln());
+/// assert_eq!(log_likelihood(&Bag::new(1, 0, 0), &game), f64::NEG_INFINITY);
+///
+/// // A hand bigger than the whole bag can't be drawn from it either.
+/// let (_, game) = Game::parse("Game 1: 2 red").unwrap();
+/// assert_eq!(log_likelihood(&Bag::new(1, 0, 0), &game), f64::NEG_INFINITY);
+/// ```
+pub fn log_likelihood(bag: &Bag, game: &Game) -> f64 {
+    LnFactorials::up_to(bag.cubes()).ln_likelihood(bag, &hands(game))
+}
+
+/// A bag the game could have been played with, and how likely the game's hands are with it.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Candidate {
+    pub bag: Bag,
+    pub log_likelihood: f64,
+}
+
+impl Candidate {
+    pub fn likelihood(&self) -> f64 {
+        self.log_likelihood.exp()
+    }
+}
+
+/// Every bag of up to `max_cubes` cubes the game could have been played with, the most likely
+/// first, and the smaller bag first between ones that are as likely as each other.
+///
+/// The most likely bag is often bigger than the smallest one, as a bag with only just enough of a
+/// color makes drawing most of them unlikely, so `max_cubes` wants to be well past the min bag.
+pub fn infer(game: &Game, max_cubes: usize) -> Vec<Candidate> {
+    let hands = hands(game);
+    let min_bag = game.min_bag();
+    let ln = LnFactorials::up_to(max_cubes);
+
+    let mut candidates = vec![];
+    for red in min_bag[Color::Red]..=max_cubes {
+        for green in min_bag[Color::Green]..=max_cubes - red {
+            for blue in min_bag[Color::Blue]..=max_cubes - red - green {
+                let bag = Bag::new(red, green, blue);
+                candidates.push(Candidate {
+                    bag,
+                    log_likelihood: ln.ln_likelihood(&bag, &hands),
+                });
+            }
+        }
+    }
+    candidates.sort_by(|a, b| {
+        (b.log_likelihood)
+            .total_cmp(&a.log_likelihood)
+            .then(a.bag.cubes().cmp(&b.bag.cubes()))
+    });
+    candidates
+}
+
+/// Draws `size` cubes out of `bag` at once.
+///
+/// # Panics
+///
+/// If there aren't `size` cubes in the bag.
+pub fn draw(bag: &Bag, size: usize, rng: &mut impl Rng) -> Bag {
+    let mut cubes = Color::ALL
+        .iter()
+        .flat_map(|&color| std::iter::repeat(color).take(bag[color]))
+        .collect::<Vec<_>>();
+    assert!(
+        size <= cubes.len(),
+        "can't draw {size} cubes out of a bag of {}",
+        cubes.len()
+    );
+    let (drawn, _) = cubes.partial_shuffle(rng, size);
+    let mut hand = Bag::default();
+    for &color in drawn.iter() {
+        hand[color] += 1;
+    }
+    hand
+}
+
+/// A line of puzzle input for a game played with `bag`, with a hand of each of `hand_sizes`.
+///
+/// # Panics
+///
+/// If a hand has no cubes, which the input has no way of writing, or more than the bag.
+pub fn simulate_game(
+    game_num: usize,
+    bag: &Bag,
+    hand_sizes: &[usize],
+    rng: &mut impl Rng,
+) -> String {
+    let hands = hand_sizes
+        .iter()
+        .map(|&size| {
+            assert!(size > 0, "hands have at least one cube in them");
+            let hand = draw(bag, size, rng);
+            let counts = Color::ALL
+                .iter()
+                .filter(|&&color| hand[color] > 0)
+                .map(|&color| format!("{} {color}", hand[color]));
+            counts.collect::<Vec<_>>().join(", ")
+        })
+        .collect::<Vec<_>>();
+    format!("Game {game_num}: {}", hands.join("; "))
+}
+
+/// How well [`infer`] did at guessing the bags of games drawn from a known one.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Validation {
+    pub trials: usize,
+    /// How many times the most likely bag was the real one.
+    pub exact: usize,
+    /// How many cubes out the most likely bag was on average, adding up how far out each color
+    /// was.
+    pub mean_error: f64,
+    /// How many cubes out part two's min bag was on average, to compare with.
+    pub min_bag_mean_error: f64,
+}
+
+impl std::fmt::Display for Validation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "exactly right {}/{} times, {:.2} cubes out on average, where the min bag was {:.2} out",
+            self.exact, self.trials, self.mean_error, self.min_bag_mean_error
+        )
+    }
+}
+
+/// Plays `trials` games with `bag`, a hand of each of `hand_sizes`, and sees how close [`infer`]
+/// gets to `bag` from them, looking at bags of up to `max_cubes`.
+pub fn validate(
+    bag: &Bag,
+    hand_sizes: &[usize],
+    max_cubes: usize,
+    trials: usize,
+    rng: &mut impl Rng,
+) -> Validation {
+    let error = |guess: Bag| {
+        Color::ALL
+            .iter()
+            .map(|&color| guess[color].abs_diff(bag[color]))
+            .sum::<usize>()
+    };
+    let mut exact = 0;
+    let mut total_error = 0;
+    let mut min_bag_error = 0;
+    for trial in 0..trials {
+        let line = simulate_game(trial + 1, bag, hand_sizes, rng);
+        let (_, game) = crate::parse_games(&line)
+            .next()
+            .expect("a simulated game is a line of input");
+        let best = infer(&game, max_cubes)
+            .first()
+            .map_or(Bag::default(), |candidate| candidate.bag);
+        exact += usize::from(best == *bag);
+        total_error += error(best);
+        min_bag_error += error(game.min_bag());
+    }
+    let mean = |error: usize| error as f64 / trials.max(1) as f64;
+    Validation {
+        trials,
+        exact,
+        mean_error: mean(total_error),
+        min_bag_mean_error: mean(min_bag_error),
+    }
+}
+
+mod test {
+
+    #[test]
+    fn ranks_bags_by_likelihood() {
+        use crate::infer::infer;
+        use crate::parse_games;
+
+        let input = include_str!("../examples/1.txt");
+        let (_, game) = parse_games(input).next().unwrap();
+        let candidates = infer(&game, 16);
+        assert!(candidates
+            .windows(2)
+            .all(|pair| pair[0].log_likelihood >= pair[1].log_likelihood));
+        let total = candidates.iter().map(|candidate| candidate.likelihood());
+        assert!(total.sum::<f64>() > 0.0);
+
+        let top = candidates[..4]
+            .iter()
+            .map(|candidate| format!("{}: {:.3}", candidate.bag, candidate.log_likelihood))
+            .collect::<Vec<_>>();
+        insta::assert_snapshot!(top.join("\n"), @"
+        5 red, 3 green, 8 blue: -10.704
+        4 red, 3 green, 8 blue: -11.001
+        5 red, 3 green, 7 blue: -11.024
+        4 red, 3 green, 9 blue: -11.032
+        ");
+    }
+
+    #[test]
+    fn simulated_games_are_drawn_from_the_bag() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        use crate::infer::simulate_game;
+        use crate::{parse_games, Bag, Color};
+
+        let mut rng = StdRng::seed_from_u64(2);
+        let bag = Bag::new(5, 4, 3);
+        let line = simulate_game(7, &bag, &[6, 12, 1], &mut rng);
+        let (_, game) = parse_games(&line).next().unwrap();
+        assert_eq!(game.game_num, 7);
+        let hands = game
+            .hands
+            .iter()
+            .map(|hand| hand.min_bag())
+            .collect::<Vec<_>>();
+        assert_eq!(hands.iter().map(Bag::cubes).collect::<Vec<_>>(), [6, 12, 1]);
+        // Drawing every cube can only go one way.
+        assert_eq!(hands[1], bag);
+        for hand in hands {
+            assert!(Color::ALL.iter().all(|&color| hand[color] <= bag[color]));
+        }
+    }
+
+    #[test]
+    fn finds_the_bag_games_were_drawn_from() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        use crate::infer::validate;
+        use crate::Bag;
+
+        let mut rng = StdRng::seed_from_u64(2023);
+        let bag = Bag::new(5, 4, 3);
+        let short = validate(&bag, &[6; 20], 16, 20, &mut rng);
+        let long = validate(&bag, &[6; 200], 16, 20, &mut rng);
+        assert!(long.exact >= 15, "{long}");
+        assert!(
+            long.mean_error * 4.0 < short.mean_error,
+            "{short}, then {long}"
+        );
+    }
+}
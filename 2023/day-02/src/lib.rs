@@ -0,0 +1,244 @@
+use std::ops::{Index, IndexMut};
+
+use aoc::{
+    answer::{Accumulate, Answer, Overflow},
+    parse::{parse_lines, Mode, Parse, ParseLines},
+};
+
+pub mod infer;
+pub mod part_one;
+pub mod part_two;
+pub mod query;
+pub mod validate;
+
+aoc_macros::example_tests! {
+    part_one: crate::part_one::part_one_for_elf,
+    part_two: crate::part_two::part_two,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Parse)]
+pub enum Color {
+    #[format("red")]
+    Red,
+    #[format("blue")]
+    Blue,
+    #[format("green")]
+    Green,
+}
+
+impl Color {
+    pub const ALL: [Color; 3] = [Color::Red, Color::Blue, Color::Green];
+}
+
+impl std::fmt::Display for Color {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Color::Red => "red",
+            Color::Blue => "blue",
+            Color::Green => "green",
+        })
+    }
+}
+
+/// How many cubes of each color are in the bag, stored inline so building one per game is free.
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct Bag([usize; 3]);
+
+impl Bag {
+    pub const fn new(red: usize, green: usize, blue: usize) -> Self {
+        let mut counts = [0; 3];
+        counts[Color::Red as usize] = red;
+        counts[Color::Green as usize] = green;
+        counts[Color::Blue as usize] = blue;
+        Self(counts)
+    }
+
+    /// How many cubes there are in all.
+    pub fn cubes(&self) -> usize {
+        self.0.iter().sum()
+    }
+
+    /// The product of the cube counts, which is what part two sums up.
+    pub fn power(&self) -> Result<Answer, Overflow> {
+        self.0.iter().copied().product_answers()
+    }
+}
+
+impl std::fmt::Display for Bag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} red, {} green, {} blue",
+            self[Color::Red],
+            self[Color::Green],
+            self[Color::Blue]
+        )
+    }
+}
+
+impl Index<Color> for Bag {
+    type Output = usize;
+
+    fn index(&self, color: Color) -> &usize {
+        &self.0[color as usize]
+    }
+}
+
+impl IndexMut<Color> for Bag {
+    fn index_mut(&mut self, color: Color) -> &mut usize {
+        &mut self.0[color as usize]
+    }
+}
+
+impl<const N: usize> From<[(Color, usize); N]> for Bag {
+    fn from(counts: [(Color, usize); N]) -> Self {
+        let mut bag = Bag::default();
+        for (color, count) in counts {
+            bag[color] = count;
+        }
+        bag
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Parse)]
+#[format("{count} {color}")]
+pub struct ColorCount {
+    pub count: usize,
+    pub color: Color,
+}
+
+/// The `, ` separated color counts of a hand, borrowed from the input and parsed lazily.
+#[derive(PartialEq, Clone, Copy)]
+pub struct ColorCounts<'a>(&'a str);
+
+impl<'a> ColorCounts<'a> {
+    pub fn iter(&self) -> impl Iterator<Item = ColorCount> + 'a {
+        self.0.split(", ").map(|color_count| {
+            ColorCount::parse(color_count)
+                .expect("color counts are validated by Hand::parse")
+                .1
+        })
+    }
+}
+
+impl<'a> From<&'a str> for ColorCounts<'a> {
+    fn from(color_counts: &'a str) -> Self {
+        Self(color_counts)
+    }
+}
+
+impl std::fmt::Debug for ColorCounts<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Parse)]
+#[format("{color_counts}")]
+pub struct Hand<'a> {
+    #[format(sep = ", ", item = ColorCount, recognize)]
+    pub color_counts: ColorCounts<'a>,
+}
+
+impl Hand<'_> {
+    /// The smallest bag this hand could have been drawn from.
+    pub fn min_bag(&self) -> Bag {
+        let mut bag = Bag::default();
+        for color_count in self.color_counts.iter() {
+            bag[color_count.color] = bag[color_count.color].max(color_count.count);
+        }
+        bag
+    }
+}
+
+impl std::fmt::Display for Hand<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.color_counts.0)
+    }
+}
+
+/// The `; ` separated hands of a game, borrowed from the input and parsed lazily.
+#[derive(PartialEq, Clone, Copy)]
+pub struct Hands<'a>(&'a str);
+
+impl<'a> Hands<'a> {
+    pub fn iter(&self) -> impl Iterator<Item = Hand<'a>> + 'a {
+        self.0.split("; ").map(|hand| {
+            Hand::parse(hand)
+                .expect("hands are validated by Game::parse")
+                .1
+        })
+    }
+}
+
+impl<'a> From<&'a str> for Hands<'a> {
+    fn from(hands: &'a str) -> Self {
+        Self(hands)
+    }
+}
+
+impl std::fmt::Debug for Hands<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy, Parse)]
+#[format("Game {game_num}: {hands}")]
+pub struct Game<'a> {
+    pub game_num: usize,
+    #[format(sep = "; ", item = Hand<'a>, recognize)]
+    pub hands: Hands<'a>,
+}
+
+impl Game<'_> {
+    /// The smallest bag every hand of this game could have been drawn from.
+    ///
+    /// confusingly this takes the maximum count of each color, since the min bag has to fit the biggest hand.
+    pub fn min_bag(&self) -> Bag {
+        self.hands.iter().fold(Bag::default(), |mut min_bag, hand| {
+            let hand_bag = hand.min_bag();
+            for color in Color::ALL {
+                min_bag[color] = min_bag[color].max(hand_bag[color]);
+            }
+            min_bag
+        })
+    }
+}
+
+/// Parses every game in the input, with its line, [`finish`](ParseLines::finish)ing with the lines
+/// that aren't games.
+pub fn parse_games(input: &str) -> ParseLines<'_, Game<'_>> {
+    parse_lines(input, Mode::Strict)
+}
+
+mod test {
+
+    #[test]
+    fn snapshot_game_parse() {
+        use aoc::parse::Parse;
+
+        use crate::Game;
+
+        let input_1 = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
+        let input_2 = "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue";
+        let input_3 = "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red";
+        let input_4 = "Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red";
+        let input_5 = "Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+
+        insta::assert_debug_snapshot!(Game::parse(input_1));
+        insta::assert_debug_snapshot!(Game::parse(input_2));
+        insta::assert_debug_snapshot!(Game::parse(input_3));
+        insta::assert_debug_snapshot!(Game::parse(input_4));
+        insta::assert_debug_snapshot!(Game::parse(input_5));
+    }
+
+    #[test]
+    fn unknown_color_is_a_parse_error() {
+        use aoc::parse::Parse;
+
+        use crate::Game;
+
+        assert!(Game::parse("Game 1: 3 purple").is_err());
+    }
+}
@@ -1,3 +1 @@
-fn main() {
-    todo!()
-}
+aoc::main!(day_two);
@@ -0,0 +1,120 @@
+use aoc::{
+    answer::{Accumulate, Answer},
+    explain::Explanation,
+    solution::SolveError,
+};
+use aoc_macros::aoc;
+
+use crate::{parse_games, Bag, Game, Hand};
+
+/// The bag the elf asks about, with only 12 red cubes, 13 green cubes, and 14 blue cubes.
+pub const BAG: Bag = Bag::new(12, 13, 14);
+
+/// The first hand (counting from 1) that has more of a color than the bag holds, which makes the game impossible.
+fn infeasible_hand<'a>(bag: &Bag, game: &Game<'a>) -> Option<(usize, Hand<'a>)> {
+    game.hands
+        .iter()
+        .enumerate()
+        .map(|(index, hand)| (index + 1, hand))
+        .find(|(_, hand)| {
+            hand.color_counts
+                .iter()
+                .any(|color_count| bag[color_count.color] < color_count.count)
+        })
+}
+
+/// In part one, we need to filter out the games that have hands which are impossible,
+/// i.e. they have more of a single color than are actually in the bag.
+///
+/// Then we need to sum the game_num of each game to get our output.
+pub fn part_one(bag: Bag, input: &str) -> Result<Answer, SolveError> {
+    let mut games = parse_games(input);
+    let valid_games = games
+        .by_ref()
+        .filter(|(_, game)| infeasible_hand(&bag, game).is_none());
+
+    let total = valid_games.map(|(_, game)| game.game_num).sum_answers()?;
+    games.finish()?;
+    Ok(total)
+}
+
+/// Part one for the elf's [`BAG`], which is the answer the puzzle wants.
+#[aoc(2023, 2, 1, explain = explain_part_one_for_elf)]
+pub fn part_one_for_elf(input: &str) -> Result<Answer, SolveError> {
+    part_one(BAG, input)
+}
+
+pub fn explain_part_one_for_elf(input: &str) -> Explanation {
+    explain_part_one(BAG, input)
+}
+
+/// Which hand, if any, made each game impossible.
+pub fn explain_part_one(bag: Bag, input: &str) -> Explanation {
+    let mut explanation = Explanation::new(&["game", "infeasible hand"]);
+    let games = parse_games(input).collect_all();
+    for (line, game) in games.unwrap_or_else(|errors| panic!("{errors}")) {
+        match infeasible_hand(&bag, &game) {
+            Some((hand_num, hand)) => explanation.push(
+                line,
+                vec![game.game_num.to_string(), format!("{hand_num}: {hand}")],
+                0,
+            ),
+            None => explanation.push(
+                line,
+                vec![game.game_num.to_string(), String::new()],
+                game.game_num,
+            ),
+        }
+    }
+    explanation
+}
+
+mod test {
+
+    #[test]
+    fn test_example_part_one() {
+        use crate::part_one::part_one;
+        use crate::Bag;
+        use crate::Color;
+
+        let bag = Bag::from([(Color::Red, 12), (Color::Green, 13), (Color::Blue, 14)]);
+        let input = aoc::input!();
+        assert_eq!(part_one(bag, &input).unwrap(), 2239usize);
+    }
+
+    #[test]
+    fn full_input_does_not_allocate() {
+        use alloc_counter::allocations_during;
+
+        use crate::part_one::part_one;
+        use crate::Bag;
+        use crate::Color;
+
+        let bag = Bag::from([(Color::Red, 12), (Color::Green, 13), (Color::Blue, 14)]);
+        let input = aoc::input!();
+        let (answer, allocations) = allocations_during(|| part_one(bag, &input));
+        assert_eq!((answer.unwrap(), allocations), (2239usize.into(), 0));
+    }
+
+    #[test]
+    fn explain_initial_example_part_one() {
+        use crate::part_one::explain_part_one;
+        use crate::Bag;
+        use crate::Color;
+
+        let bag = Bag::from([(Color::Red, 12), (Color::Green, 14), (Color::Blue, 14)]);
+        let input = include_str!("../examples/1.txt");
+        let explanation = explain_part_one(bag, input);
+        assert_eq!(explanation.total().unwrap(), 8);
+        insta::assert_snapshot!(explanation.to_string(), @"
+        line │ game │ infeasible hand             │ contribution
+        ─────┼──────┼─────────────────────────────┼─────────────
+        1    │ 1    │                             │ 1
+        2    │ 2    │                             │ 2
+        3    │ 3    │ 1: 8 green, 6 blue, 20 red  │ 0
+        4    │ 4    │ 3: 3 green, 15 blue, 14 red │ 0
+        5    │ 5    │                             │ 5
+        total: 8
+        ");
+    }
+}
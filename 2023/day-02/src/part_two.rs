@@ -0,0 +1,83 @@
+use aoc::{answer::Answer, explain::Explanation, solution::SolveError};
+use aoc_macros::aoc;
+
+use crate::{parse_games, Color};
+
+/// In part two, we need to find the min bag for each game, and then do fancy math to them.
+#[aoc(2023, 2, 2, explain = explain_part_two)]
+pub fn part_two(input: &str) -> Result<Answer, SolveError> {
+    let mut games = parse_games(input);
+    let total = games.by_ref().try_fold(Answer::ZERO, |total, (_, game)| {
+        total.checked_add(game.min_bag().power()?)
+    })?;
+    games.finish()?;
+    Ok(total)
+}
+
+/// The min bag of each game, whose power is what it adds.
+pub fn explain_part_two(input: &str) -> Explanation {
+    let mut explanation = Explanation::new(&["game", "red", "green", "blue"]);
+    let games = parse_games(input).collect_all();
+    for (line, game) in games.unwrap_or_else(|errors| panic!("{errors}")) {
+        let min_bag = game.min_bag();
+        let power = min_bag.power().unwrap_or_else(|overflow| {
+            panic!("game {}'s power overflowed: {overflow}", game.game_num)
+        });
+        explanation.push(
+            line,
+            vec![
+                game.game_num.to_string(),
+                min_bag[Color::Red].to_string(),
+                min_bag[Color::Green].to_string(),
+                min_bag[Color::Blue].to_string(),
+            ],
+            power,
+        );
+    }
+    explanation
+}
+
+mod test {
+
+    #[test]
+    fn provided_input() {
+        use crate::part_two::part_two;
+
+        let input = aoc::input!();
+        assert_eq!(part_two(&input).unwrap(), 83435usize);
+    }
+
+    #[test]
+    fn full_input_does_not_allocate() {
+        use alloc_counter::allocations_during;
+
+        use crate::part_two::part_two;
+
+        let input = aoc::input!();
+        let (answer, allocations) = allocations_during(|| part_two(&input));
+        assert_eq!((answer.unwrap(), allocations), (83435usize.into(), 0));
+    }
+
+    #[test]
+    fn powers_that_overflow_are_errors() {
+        use crate::part_two::part_two;
+
+        let max = u64::MAX;
+        let input = format!("Game 1: {max} red, {max} green, 2 blue");
+        assert!(part_two(&input).is_err());
+    }
+
+    #[test]
+    fn bad_games_are_errors() {
+        use aoc::solution::SolveError;
+
+        use crate::part_two::part_two;
+
+        let input = "Game 1: 1 red\nGame 2: 1 purple\nGame 3 2 blue";
+        let Err(SolveError::Parse(errors)) = part_two(input) else {
+            panic!("bad games should be parse errors");
+        };
+        let lines = errors.0.iter().map(|error| error.line).collect::<Vec<_>>();
+        assert_eq!(lines, [2, 3]);
+    }
+}
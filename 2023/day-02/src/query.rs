@@ -0,0 +1,984 @@
+//! A little language for asking questions about the games without writing a part for each, like
+//! which games had more than 12 red cubes in a hand, `max(red) > 12`, or what the powers of the
+//! even games add up to, `sum(power) where game % 2 == 0`.
+//!
+//! Everything a query can name is about every game, one game, or one hand of a game:
+//!
+//! - every game: `games`, how many there are
+//! - a game: `game`, its number, `hands`, how many hands it has, and `power`, part two's product of
+//!   the most cubes of each color shown in a hand
+//! - a hand: `hand`, its number in the game from 1, `red`, `green`, `blue`, and `cubes`, how many
+//!   cubes it has in all
+//!
+//! `sum`, `product`, `max`, `min`, `count`, `any` and `all` go over the hands of a game, or over the
+//! games, whichever of those what's in them is about, so `max(red)` is per game and `sum(power)` is
+//! about every game. `any_hand`, `all_hands`, `any_game` and `all_games` say which they go over.
+//! Otherwise there's arithmetic, `+ - * / %`, comparisons, `== != < <= > >=`, and `and`, `or` and
+//! `not`.
+//!
+//! A query about every game has one answer. A query per game is answered for each game: the games
+//! it's true for, or what it is for each of them. `where` picks which games a query is over.
+//!
+//! ```
+//! use day_two::query::{Query, QueryOutput};
+//!
+//! let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+//! Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+//! Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red";
+//!
+//! let query = Query::parse("sum(power) where game % 2 == 1").unwrap();
+//! assert_eq!(query.run(input).unwrap(), QueryOutput::Number(48 + 1560));
+//!
+//! let query = Query::parse("max(red) > 12 or any_hand(blue >= 6)").unwrap();
+//! assert_eq!(query.run(input).unwrap(), QueryOutput::Games(vec![1, 3]));
+//! ```
+
+use std::{fmt, ops::Range, str::FromStr};
+
+use crate::{parse_games, Color, Game, Hand};
+
+/// What something in a query is about.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Level {
+    Games,
+    Game,
+    Hand,
+}
+
+impl Level {
+    /// What an aggregate of things at this level is about.
+    fn above(self) -> Option<Level> {
+        match self {
+            Level::Games => None,
+            Level::Game => Some(Level::Games),
+            Level::Hand => Some(Level::Game),
+        }
+    }
+
+    fn describe(self) -> &'static str {
+        match self {
+            Level::Games => "about every game",
+            Level::Game => "per game",
+            Level::Hand => "per hand",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Type {
+    Number,
+    Bool,
+}
+
+impl Type {
+    fn describe(self) -> &'static str {
+        match self {
+            Type::Number => "a number",
+            Type::Bool => "true or false",
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Value {
+    Number(i64),
+    Bool(bool),
+}
+
+impl Value {
+    fn number(self) -> i64 {
+        match self {
+            Value::Number(number) => number,
+            Value::Bool(_) => unreachable!("queries are type checked before they're run"),
+        }
+    }
+
+    fn bool(self) -> bool {
+        match self {
+            Value::Bool(bool) => bool,
+            Value::Number(_) => unreachable!("queries are type checked before they're run"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Name {
+    Games,
+    Game,
+    Hands,
+    Power,
+    Hand,
+    Color(Color),
+    Cubes,
+}
+
+const NAMES: &[(&str, Name, Level)] = &[
+    ("games", Name::Games, Level::Games),
+    ("game", Name::Game, Level::Game),
+    ("hands", Name::Hands, Level::Game),
+    ("power", Name::Power, Level::Game),
+    ("hand", Name::Hand, Level::Hand),
+    ("red", Name::Color(Color::Red), Level::Hand),
+    ("green", Name::Color(Color::Green), Level::Hand),
+    ("blue", Name::Color(Color::Blue), Level::Hand),
+    ("cubes", Name::Cubes, Level::Hand),
+];
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Aggregate {
+    Sum,
+    Product,
+    Max,
+    Min,
+    Count,
+    Any,
+    All,
+}
+
+impl Aggregate {
+    /// What it aggregates.
+    fn takes(self) -> Type {
+        match self {
+            Aggregate::Sum | Aggregate::Product | Aggregate::Max | Aggregate::Min => Type::Number,
+            Aggregate::Count | Aggregate::Any | Aggregate::All => Type::Bool,
+        }
+    }
+
+    fn gives(self) -> Type {
+        match self {
+            Aggregate::Any | Aggregate::All => Type::Bool,
+            _ => Type::Number,
+        }
+    }
+}
+
+/// The aggregates, and what they go over if that's in their name.
+const AGGREGATES: &[(&str, Aggregate, Option<Level>)] = &[
+    ("sum", Aggregate::Sum, None),
+    ("product", Aggregate::Product, None),
+    ("max", Aggregate::Max, None),
+    ("min", Aggregate::Min, None),
+    ("count", Aggregate::Count, None),
+    ("any", Aggregate::Any, None),
+    ("all", Aggregate::All, None),
+    ("any_hand", Aggregate::Any, Some(Level::Hand)),
+    ("all_hands", Aggregate::All, Some(Level::Hand)),
+    ("any_game", Aggregate::Any, Some(Level::Game)),
+    ("all_games", Aggregate::All, Some(Level::Game)),
+];
+
+const KEYWORDS: &[&str] = &["and", "or", "not", "where", "true", "false"];
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum BinOp {
+    Or,
+    And,
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+}
+
+#[derive(Debug, Clone)]
+enum ExprKind {
+    Number(i64),
+    Bool(bool),
+    Name(Name, Level),
+    Not(Box<Expr>),
+    Neg(Box<Expr>),
+    Binary(BinOp, Box<Expr>, Box<Expr>),
+    /// The level is what the aggregate goes over, which type checking fills in.
+    Aggregate(Aggregate, &'static str, Option<Level>, Box<Expr>),
+}
+
+#[derive(Debug, Clone)]
+struct Expr {
+    kind: ExprKind,
+    span: Range<usize>,
+}
+
+/// What went wrong with a query, and where in it.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct QueryError {
+    pub at: Range<usize>,
+    pub message: String,
+}
+
+impl QueryError {
+    fn new(at: Range<usize>, message: impl Into<String>) -> Self {
+        Self {
+            at,
+            message: message.into(),
+        }
+    }
+
+    /// The query with what went wrong pointed out underneath it.
+    pub fn show(&self, query: &str) -> String {
+        let start = query[..self.at.start].chars().count();
+        let width = query[self.at.clone()].chars().count().max(1);
+        format!(
+            "{query}\n{}{} {}",
+            " ".repeat(start),
+            "^".repeat(width),
+            self.message
+        )
+    }
+}
+
+impl fmt::Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for QueryError {}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Token<'q> {
+    Number(i64),
+    Word(&'q str),
+    Symbol(&'static str),
+    End,
+}
+
+impl fmt::Display for Token<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Number(number) => write!(f, "`{number}`"),
+            Token::Word(word) => write!(f, "`{word}`"),
+            Token::Symbol(symbol) => write!(f, "`{symbol}`"),
+            Token::End => f.write_str("the end"),
+        }
+    }
+}
+
+const SYMBOLS: &[&str] = &[
+    "==", "!=", "<=", ">=", "<", ">", "(", ")", "+", "-", "*", "/", "%",
+];
+
+fn tokenize(query: &str) -> Result<Vec<(Token<'_>, Range<usize>)>, QueryError> {
+    let mut tokens = vec![];
+    let mut start = 0;
+    while let Some(c) = query[start..].chars().next() {
+        let rest = &query[start..];
+        let len = if c.is_whitespace() {
+            c.len_utf8()
+        } else if c.is_ascii_digit() {
+            let len = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            let number = rest[..len]
+                .parse()
+                .map_err(|_| QueryError::new(start..start + len, "that number's too big"))?;
+            tokens.push((Token::Number(number), start..start + len));
+            len
+        } else if c.is_ascii_alphabetic() || c == '_' {
+            let len = rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            tokens.push((Token::Word(&rest[..len]), start..start + len));
+            len
+        } else if let Some(symbol) = SYMBOLS.iter().find(|symbol| rest.starts_with(*symbol)) {
+            tokens.push((Token::Symbol(symbol), start..start + symbol.len()));
+            symbol.len()
+        } else {
+            return Err(QueryError::new(
+                start..start + c.len_utf8(),
+                format!("{c:?} doesn't mean anything in a query"),
+            ));
+        };
+        start += len;
+    }
+    tokens.push((Token::End, query.len()..query.len()));
+    Ok(tokens)
+}
+
+struct Parser<'q> {
+    tokens: Vec<(Token<'q>, Range<usize>)>,
+    at: usize,
+}
+
+impl<'q> Parser<'q> {
+    fn peek(&self) -> Token<'q> {
+        self.tokens[self.at].0
+    }
+
+    fn span(&self) -> Range<usize> {
+        self.tokens[self.at].1.clone()
+    }
+
+    fn next(&mut self) -> (Token<'q>, Range<usize>) {
+        let token = self.tokens[self.at].clone();
+        if token.0 != Token::End {
+            self.at += 1;
+        }
+        token
+    }
+
+    /// Moves past `token` if it's next.
+    fn eat(&mut self, token: Token) -> bool {
+        let next = self.peek() == token;
+        if next {
+            self.next();
+        }
+        next
+    }
+
+    fn expect(&mut self, token: Token, what: &str) -> Result<Range<usize>, QueryError> {
+        if self.peek() == token {
+            Ok(self.next().1)
+        } else {
+            Err(self.unexpected(what))
+        }
+    }
+
+    fn unexpected(&self, what: &str) -> QueryError {
+        QueryError::new(
+            self.span(),
+            format!("expected {what}, found {}", self.peek()),
+        )
+    }
+
+    fn binary(op: BinOp, lhs: Expr, rhs: Expr) -> Expr {
+        Expr {
+            span: lhs.span.start..rhs.span.end,
+            kind: ExprKind::Binary(op, Box::new(lhs), Box::new(rhs)),
+        }
+    }
+
+    fn or(&mut self) -> Result<Expr, QueryError> {
+        let mut expr = self.and()?;
+        while self.eat(Token::Word("or")) {
+            expr = Self::binary(BinOp::Or, expr, self.and()?);
+        }
+        Ok(expr)
+    }
+
+    fn and(&mut self) -> Result<Expr, QueryError> {
+        let mut expr = self.not()?;
+        while self.eat(Token::Word("and")) {
+            expr = Self::binary(BinOp::And, expr, self.not()?);
+        }
+        Ok(expr)
+    }
+
+    fn not(&mut self) -> Result<Expr, QueryError> {
+        if self.peek() == Token::Word("not") {
+            let start = self.next().1.start;
+            let expr = self.not()?;
+            return Ok(Expr {
+                span: start..expr.span.end,
+                kind: ExprKind::Not(Box::new(expr)),
+            });
+        }
+        self.comparison()
+    }
+
+    /// Comparisons don't chain, `1 < red < 5` has to be `1 < red and red < 5`.
+    fn comparison(&mut self) -> Result<Expr, QueryError> {
+        let expr = self.sum()?;
+        let op = match self.peek() {
+            Token::Symbol("==") => BinOp::Eq,
+            Token::Symbol("!=") => BinOp::Ne,
+            Token::Symbol("<") => BinOp::Lt,
+            Token::Symbol("<=") => BinOp::Le,
+            Token::Symbol(">") => BinOp::Gt,
+            Token::Symbol(">=") => BinOp::Ge,
+            _ => return Ok(expr),
+        };
+        self.next();
+        Ok(Self::binary(op, expr, self.sum()?))
+    }
+
+    fn sum(&mut self) -> Result<Expr, QueryError> {
+        let mut expr = self.product()?;
+        loop {
+            let op = match self.peek() {
+                Token::Symbol("+") => BinOp::Add,
+                Token::Symbol("-") => BinOp::Sub,
+                _ => return Ok(expr),
+            };
+            self.next();
+            expr = Self::binary(op, expr, self.product()?);
+        }
+    }
+
+    fn product(&mut self) -> Result<Expr, QueryError> {
+        let mut expr = self.negation()?;
+        loop {
+            let op = match self.peek() {
+                Token::Symbol("*") => BinOp::Mul,
+                Token::Symbol("/") => BinOp::Div,
+                Token::Symbol("%") => BinOp::Rem,
+                _ => return Ok(expr),
+            };
+            self.next();
+            expr = Self::binary(op, expr, self.negation()?);
+        }
+    }
+
+    fn negation(&mut self) -> Result<Expr, QueryError> {
+        if self.peek() == Token::Symbol("-") {
+            let start = self.next().1.start;
+            let expr = self.negation()?;
+            return Ok(Expr {
+                span: start..expr.span.end,
+                kind: ExprKind::Neg(Box::new(expr)),
+            });
+        }
+        self.primary()
+    }
+
+    fn primary(&mut self) -> Result<Expr, QueryError> {
+        let (token, span) = self.next();
+        let kind = match token {
+            Token::Number(number) => ExprKind::Number(number),
+            Token::Word("true") => ExprKind::Bool(true),
+            Token::Word("false") => ExprKind::Bool(false),
+            Token::Symbol("(") => {
+                let expr = self.or()?;
+                let end = self.expect(Token::Symbol(")"), "`)`")?.end;
+                return Ok(Expr {
+                    span: span.start..end,
+                    kind: expr.kind,
+                });
+            }
+            Token::Word(word) if self.peek() == Token::Symbol("(") => {
+                let Some(&(name, aggregate, over)) =
+                    AGGREGATES.iter().find(|(name, ..)| *name == word)
+                else {
+                    let names = AGGREGATES.iter().map(|(name, ..)| *name);
+                    return Err(QueryError::new(
+                        span,
+                        format!(
+                            "there's no function `{word}`, there's {}",
+                            names.collect::<Vec<_>>().join(", ")
+                        ),
+                    ));
+                };
+                self.next();
+                let arg = self.or()?;
+                let end = self.expect(Token::Symbol(")"), "`)`")?.end;
+                return Ok(Expr {
+                    kind: ExprKind::Aggregate(aggregate, name, over, Box::new(arg)),
+                    span: span.start..end,
+                });
+            }
+            Token::Word(word) if !KEYWORDS.contains(&word) => {
+                match NAMES.iter().find(|(name, ..)| *name == word) {
+                    Some(&(_, name, level)) => ExprKind::Name(name, level),
+                    None => {
+                        let names = NAMES.iter().map(|(name, ..)| *name);
+                        return Err(QueryError::new(
+                            span,
+                            format!(
+                                "there's nothing called `{word}`, there's {}",
+                                names.collect::<Vec<_>>().join(", ")
+                            ),
+                        ));
+                    }
+                }
+            }
+            token => {
+                return Err(QueryError::new(
+                    span,
+                    format!("expected a number, a name or `(`, found {token}"),
+                ))
+            }
+        };
+        Ok(Expr { kind, span })
+    }
+}
+
+/// What an expression gives and what it's about, `None` if it's the same whatever it's about.
+fn check(expr: &mut Expr) -> Result<(Type, Option<Level>), QueryError> {
+    let span = expr.span.clone();
+    let expect = |expr: &mut Expr, expected: Type| {
+        let (ty, level) = check(expr)?;
+        if ty != expected {
+            return Err(QueryError::new(
+                expr.span.clone(),
+                format!(
+                    "expected {}, but this is {}",
+                    expected.describe(),
+                    ty.describe()
+                ),
+            ));
+        }
+        Ok(level)
+    };
+    Ok(match &mut expr.kind {
+        ExprKind::Number(_) => (Type::Number, None),
+        ExprKind::Bool(_) => (Type::Bool, None),
+        ExprKind::Name(_, level) => (Type::Number, Some(*level)),
+        ExprKind::Not(expr) => (Type::Bool, expect(expr, Type::Bool)?),
+        ExprKind::Neg(expr) => (Type::Number, expect(expr, Type::Number)?),
+        ExprKind::Binary(op, lhs, rhs) => {
+            let (ty, (lhs_level, rhs_level)) = match op {
+                BinOp::Or | BinOp::And => (
+                    Type::Bool,
+                    (expect(lhs, Type::Bool)?, expect(rhs, Type::Bool)?),
+                ),
+                BinOp::Eq | BinOp::Ne => {
+                    let (ty, lhs_level) = check(lhs)?;
+                    (Type::Bool, (lhs_level, expect(rhs, ty)?))
+                }
+                BinOp::Lt | BinOp::Le | BinOp::Gt | BinOp::Ge => (
+                    Type::Bool,
+                    (expect(lhs, Type::Number)?, expect(rhs, Type::Number)?),
+                ),
+                BinOp::Add | BinOp::Sub | BinOp::Mul | BinOp::Div | BinOp::Rem => (
+                    Type::Number,
+                    (expect(lhs, Type::Number)?, expect(rhs, Type::Number)?),
+                ),
+            };
+            match (lhs_level, rhs_level) {
+                (Some(lhs_level), Some(rhs_level)) if lhs_level != rhs_level => {
+                    return Err(QueryError::new(
+                        span,
+                        format!(
+                            "this mixes something {} with something {}, \
+                             which needs one of them aggregating, like `max(red)`",
+                            lhs_level.describe(),
+                            rhs_level.describe(),
+                        ),
+                    ))
+                }
+                (lhs_level, rhs_level) => (ty, lhs_level.or(rhs_level)),
+            }
+        }
+        ExprKind::Aggregate(aggregate, name, over, arg) => {
+            let level = expect(arg, aggregate.takes())?;
+            let above = match level {
+                None => {
+                    return Err(QueryError::new(
+                        span,
+                        format!("`{name}` needs something per game or per hand to go over"),
+                    ))
+                }
+                Some(level) if over.is_some_and(|over| over != level) => {
+                    return Err(QueryError::new(
+                        arg.span.clone(),
+                        format!(
+                            "`{name}` goes over the {}s, but this is {}",
+                            if *over == Some(Level::Hand) { "hand" } else { "game" },
+                            level.describe()
+                        ),
+                    ))
+                }
+                Some(level) => level.above().ok_or_else(|| {
+                    QueryError::new(
+                        arg.span.clone(),
+                        format!("there's nothing to go over with `{name}`, this is already about every game"),
+                    )
+                })?,
+            };
+            *over = level;
+            (aggregate.gives(), Some(above))
+        }
+    })
+}
+
+/// What a query is answered about: the games it's over, and the game and hand if it's per game or
+/// per hand.
+#[derive(Clone, Copy)]
+struct Scope<'s, 'a> {
+    games: &'s [Game<'a>],
+    game: Option<&'s Game<'a>>,
+    hand: Option<(usize, Hand<'a>)>,
+}
+
+impl<'s, 'a> Scope<'s, 'a> {
+    fn game(&self) -> &'s Game<'a> {
+        self.game
+            .expect("queries are level checked before they're run")
+    }
+
+    fn hand(&self) -> (usize, Hand<'a>) {
+        self.hand
+            .expect("queries are level checked before they're run")
+    }
+
+    /// A scope for each of the things at `level` in this one.
+    fn each(self, level: Level) -> Vec<Scope<'s, 'a>> {
+        match level {
+            Level::Games => vec![self],
+            Level::Game => self
+                .games
+                .iter()
+                .map(|game| Scope {
+                    game: Some(game),
+                    ..self
+                })
+                .collect(),
+            Level::Hand => self
+                .game()
+                .hands
+                .iter()
+                .enumerate()
+                .map(|(index, hand)| Scope {
+                    hand: Some((index + 1, hand)),
+                    ..self
+                })
+                .collect(),
+        }
+    }
+}
+
+fn count(count: usize, span: &Range<usize>) -> Result<Value, QueryError> {
+    i64::try_from(count)
+        .map(Value::Number)
+        .map_err(|_| QueryError::new(span.clone(), "that's too many to count"))
+}
+
+fn eval(expr: &Expr, scope: Scope) -> Result<Value, QueryError> {
+    let overflow = || QueryError::new(expr.span.clone(), "this overflowed");
+    Ok(match &expr.kind {
+        ExprKind::Number(number) => Value::Number(*number),
+        ExprKind::Bool(bool) => Value::Bool(*bool),
+        ExprKind::Name(name, _) => match name {
+            Name::Games => count(scope.games.len(), &expr.span)?,
+            Name::Game => count(scope.game().game_num, &expr.span)?,
+            Name::Hands => count(scope.game().hands.iter().count(), &expr.span)?,
+            Name::Power => scope
+                .game()
+                .min_bag()
+                .power()
+                .ok()
+                .and_then(|power| i64::try_from(power.to_i128()?).ok())
+                .map(Value::Number)
+                .ok_or_else(overflow)?,
+            Name::Hand => count(scope.hand().0, &expr.span)?,
+            Name::Color(color) => count(scope.hand().1.min_bag()[*color], &expr.span)?,
+            Name::Cubes => count(
+                scope.hand().1.color_counts.iter().map(|cc| cc.count).sum(),
+                &expr.span,
+            )?,
+        },
+        ExprKind::Not(expr) => Value::Bool(!eval(expr, scope)?.bool()),
+        ExprKind::Neg(expr) => Value::Number(
+            eval(expr, scope)?
+                .number()
+                .checked_neg()
+                .ok_or_else(overflow)?,
+        ),
+        ExprKind::Binary(op, lhs, rhs) => {
+            let lhs = eval(lhs, scope)?;
+            // `and` and `or` only look at their right hand side if they have to.
+            match (op, lhs) {
+                (BinOp::And, Value::Bool(false)) => return Ok(Value::Bool(false)),
+                (BinOp::Or, Value::Bool(true)) => return Ok(Value::Bool(true)),
+                (BinOp::And | BinOp::Or, _) => return eval(rhs, scope),
+                _ => {}
+            }
+            let rhs = eval(rhs, scope)?;
+            let arithmetic = |op: fn(i64, i64) -> Option<i64>| {
+                op(lhs.number(), rhs.number())
+                    .map(Value::Number)
+                    .ok_or_else(overflow)
+            };
+            match op {
+                BinOp::Eq => Value::Bool(lhs == rhs),
+                BinOp::Ne => Value::Bool(lhs != rhs),
+                BinOp::Lt => Value::Bool(lhs.number() < rhs.number()),
+                BinOp::Le => Value::Bool(lhs.number() <= rhs.number()),
+                BinOp::Gt => Value::Bool(lhs.number() > rhs.number()),
+                BinOp::Ge => Value::Bool(lhs.number() >= rhs.number()),
+                BinOp::Add => arithmetic(i64::checked_add)?,
+                BinOp::Sub => arithmetic(i64::checked_sub)?,
+                BinOp::Mul => arithmetic(i64::checked_mul)?,
+                BinOp::Div | BinOp::Rem if rhs.number() == 0 => {
+                    return Err(QueryError::new(expr.span.clone(), "this divides by zero"))
+                }
+                BinOp::Div => arithmetic(i64::checked_div)?,
+                BinOp::Rem => arithmetic(i64::checked_rem)?,
+                BinOp::And | BinOp::Or => unreachable!("handled above"),
+            }
+        }
+        ExprKind::Aggregate(aggregate, name, over, arg) => {
+            let over = over.expect("queries are level checked before they're run");
+            let values = scope
+                .each(over)
+                .into_iter()
+                .map(|scope| eval(arg, scope))
+                .collect::<Result<Vec<_>, _>>()?;
+            let mut numbers = values.iter().map(|value| value.number());
+            let mut bools = values.iter().map(|value| value.bool());
+            let nothing = || {
+                QueryError::new(
+                    expr.span.clone(),
+                    format!("there's nothing to take the `{name}` of"),
+                )
+            };
+            match aggregate {
+                Aggregate::Sum => {
+                    Value::Number(numbers.try_fold(0, i64::checked_add).ok_or_else(overflow)?)
+                }
+                Aggregate::Product => {
+                    Value::Number(numbers.try_fold(1, i64::checked_mul).ok_or_else(overflow)?)
+                }
+                Aggregate::Max => Value::Number(numbers.max().ok_or_else(nothing)?),
+                Aggregate::Min => Value::Number(numbers.min().ok_or_else(nothing)?),
+                Aggregate::Count => count(bools.filter(|&bool| bool).count(), &expr.span)?,
+                Aggregate::Any => Value::Bool(bools.any(|bool| bool)),
+                Aggregate::All => Value::Bool(bools.all(|bool| bool)),
+            }
+        }
+    })
+}
+
+/// A question about the games, ready to be asked of some input.
+#[derive(Debug, Clone)]
+pub struct Query {
+    expr: Expr,
+    ty: Type,
+    level: Level,
+    filter: Option<Expr>,
+}
+
+impl Query {
+    /// Parses and checks a query, so it's only wrong before it's run if it can't be answered at all.
+    pub fn parse(query: &str) -> Result<Self, QueryError> {
+        let mut parser = Parser {
+            tokens: tokenize(query)?,
+            at: 0,
+        };
+        let mut expr = parser.or()?;
+        let mut filter = if parser.eat(Token::Word("where")) {
+            Some(parser.or()?)
+        } else {
+            None
+        };
+        if parser.peek() != Token::End {
+            return Err(parser.unexpected(if filter.is_some() {
+                "the end of the query"
+            } else {
+                "`where` or the end of the query"
+            }));
+        }
+
+        let (ty, level) = check(&mut expr)?;
+        let level =
+            match level {
+                None | Some(Level::Games) => Level::Games,
+                Some(Level::Game) => Level::Game,
+                Some(Level::Hand) => return Err(QueryError::new(
+                    expr.span,
+                    "this is per hand, which needs going over the hands, like `any_hand(...)` or \
+                     `max(...)`",
+                )),
+            };
+        if let Some(filter) = &mut filter {
+            match check(filter)? {
+                (Type::Bool, None | Some(Level::Game)) => {}
+                (Type::Number, _) => {
+                    return Err(QueryError::new(
+                        filter.span.clone(),
+                        "`where` picks games, so it needs to be true or false, not a number",
+                    ))
+                }
+                (Type::Bool, Some(level)) => {
+                    return Err(QueryError::new(
+                        filter.span.clone(),
+                        format!(
+                            "`where` picks games, so it needs to be per game, but this is {}",
+                            level.describe()
+                        ),
+                    ))
+                }
+            }
+        }
+
+        Ok(Self {
+            expr,
+            ty,
+            level,
+            filter,
+        })
+    }
+
+    /// Answers the query for the games in `input`.
+    pub fn run(&self, input: &str) -> Result<QueryOutput, QueryError> {
+        let mut games = parse_games(input)
+            .collect_all()
+            .map_err(|errors| QueryError::new(0..0, errors.to_string()))?
+            .into_iter()
+            .map(|(_, game)| game)
+            .collect::<Vec<_>>();
+        if let Some(filter) = &self.filter {
+            let mut keep = vec![];
+            for game in &games {
+                let scope = Scope {
+                    games: &[],
+                    game: Some(game),
+                    hand: None,
+                };
+                keep.push(eval(filter, scope)?.bool());
+            }
+            let mut keep = keep.into_iter();
+            games.retain(|_| keep.next().unwrap());
+        }
+        let scope = Scope {
+            games: &games,
+            game: None,
+            hand: None,
+        };
+
+        Ok(match (self.level, self.ty) {
+            (Level::Games, _) => match eval(&self.expr, scope)? {
+                Value::Number(number) => QueryOutput::Number(number),
+                Value::Bool(bool) => QueryOutput::Bool(bool),
+            },
+            (_, Type::Bool) => {
+                let mut matching = vec![];
+                for scope in scope.each(Level::Game) {
+                    if eval(&self.expr, scope)?.bool() {
+                        matching.push(scope.game().game_num);
+                    }
+                }
+                QueryOutput::Games(matching)
+            }
+            (_, Type::Number) => QueryOutput::PerGame(
+                scope
+                    .each(Level::Game)
+                    .into_iter()
+                    .map(|scope| Ok((scope.game().game_num, eval(&self.expr, scope)?.number())))
+                    .collect::<Result<_, QueryError>>()?,
+            ),
+        })
+    }
+}
+
+impl FromStr for Query {
+    type Err = QueryError;
+
+    fn from_str(query: &str) -> Result<Self, QueryError> {
+        Self::parse(query)
+    }
+}
+
+/// A query's answer.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum QueryOutput {
+    /// The answer about every game.
+    Number(i64),
+    /// The answer about every game.
+    Bool(bool),
+    /// The numbers of the games a query per game is true for.
+    Games(Vec<usize>),
+    /// The number of each game, and what a query per game is for it.
+    PerGame(Vec<(usize, i64)>),
+}
+
+impl fmt::Display for QueryOutput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            QueryOutput::Number(number) => write!(f, "{number}"),
+            QueryOutput::Bool(bool) => write!(f, "{bool}"),
+            QueryOutput::Games(games) if games.is_empty() => write!(f, "no games"),
+            QueryOutput::Games(games) => {
+                let games = games.iter().map(usize::to_string).collect::<Vec<_>>();
+                write!(f, "{} games: {}", games.len(), games.join(", "))
+            }
+            QueryOutput::PerGame(games) => {
+                let games = games
+                    .iter()
+                    .map(|(game, value)| format!("game {game}: {value}"))
+                    .collect::<Vec<_>>();
+                f.write_str(&games.join("\n"))
+            }
+        }
+    }
+}
+
+mod test {
+
+    #[test]
+    fn parts_as_queries() {
+        use crate::query::{Query, QueryOutput};
+
+        let input = aoc::input!();
+        let part_one =
+            Query::parse("sum(game) where all_hands(red <= 12 and green <= 13 and blue <= 14)");
+        assert_eq!(part_one.unwrap().run(&input), Ok(QueryOutput::Number(2239)));
+        let part_two = Query::parse("sum(power)");
+        assert_eq!(
+            part_two.unwrap().run(&input),
+            Ok(QueryOutput::Number(83435))
+        );
+    }
+
+    #[test]
+    fn answers_per_game() {
+        use crate::query::Query;
+
+        let input = include_str!("../examples/1.txt");
+        let run = |query: &str| Query::parse(query).unwrap().run(input).unwrap().to_string();
+
+        insta::assert_snapshot!(run("max(red) > 12 or any_hand(blue >= 10)"), @"2 games: 3, 4");
+        insta::assert_snapshot!(run("count(cubes > 5) where not (game == 3)"), @"
+        game 1: 2
+        game 2: 1
+        game 4: 3
+        game 5: 1
+        ");
+        insta::assert_snapshot!(run("max(hands) - min(hands) where power < 100"), @"1");
+        insta::assert_snapshot!(run("any_game(all(red < green))"), @"true");
+        insta::assert_snapshot!(run("games where 1 == 2"), @"0");
+    }
+
+    #[test]
+    fn says_what_is_wrong_with_a_query() {
+        use crate::query::Query;
+
+        let input = include_str!("../examples/1.txt");
+        let error = |query: &str| match Query::parse(query).and_then(|query| query.run(input)) {
+            Ok(output) => panic!("{query} gave {output}"),
+            Err(error) => error.show(query),
+        };
+
+        insta::assert_snapshot!(error("sum(powr)"), @"
+        sum(powr)
+            ^^^^ there's nothing called `powr`, there's games, game, hands, power, hand, red, green, blue, cubes
+        ");
+        insta::assert_snapshot!(error("red > 12"), @"
+        red > 12
+        ^^^^^^^^ this is per hand, which needs going over the hands, like `any_hand(...)` or `max(...)`
+        ");
+        insta::assert_snapshot!(error("game + red"), @"
+        game + red
+        ^^^^^^^^^^ this mixes something per game with something per hand, which needs one of them aggregating, like `max(red)`
+        ");
+        insta::assert_snapshot!(error("any_hand(power > 1)"), @"
+        any_hand(power > 1)
+                 ^^^^^^^^^ `any_hand` goes over the hands, but this is per game
+        ");
+        insta::assert_snapshot!(error("sum(game) where game"), @"
+        sum(game) where game
+                        ^^^^ `where` picks games, so it needs to be true or false, not a number
+        ");
+        insta::assert_snapshot!(error("sum(red and 1)"), @"
+        sum(red and 1)
+            ^^^ expected true or false, but this is a number
+        ");
+        insta::assert_snapshot!(error("sum(game / (game - 3))"), @"
+        sum(game / (game - 3))
+            ^^^^^^^^^^^^^^^^^ this divides by zero
+        ");
+        insta::assert_snapshot!(error("max(game) where game > 5"), @"
+        max(game) where game > 5
+        ^^^^^^^^^ there's nothing to take the `max` of
+        ");
+        insta::assert_snapshot!(error("sum(game"), @"
+        sum(game
+                ^ expected `)`, found the end
+        ");
+    }
+}
@@ -0,0 +1,91 @@
+//! What the input should look like beyond each line being a game: the games numbered 1, 2, 3 and
+//! so on, and each hand saying how many of a color it has once, and only if it has some.
+
+use aoc::{
+    parse::{parse_lines, Mode},
+    validate::Report,
+};
+use aoc_macros::aoc_validator;
+
+use crate::{Bag, Color, Game};
+
+#[aoc_validator(2023, 2)]
+pub fn validate(input: &str) -> Report {
+    let mut report = Report::default();
+    let mut game_nums = vec![];
+    let mut games = parse_lines::<Game>(input, Mode::Lenient);
+    for (line, game) in games.by_ref() {
+        game_nums.push((line, game.game_num));
+
+        for (hand_num, hand) in game.hands.iter().enumerate() {
+            let hand_num = hand_num + 1;
+            let mut named = Bag::default();
+            for color_count in hand.color_counts.iter() {
+                named[color_count.color] += 1;
+                if color_count.count == 0 {
+                    report.warn(line, format!("hand {hand_num} has 0 {}", color_count.color));
+                }
+            }
+            for color in Color::ALL {
+                if named[color] > 1 {
+                    report.warn(
+                        line,
+                        format!(
+                            "hand {hand_num} says how many {color} it has {} times",
+                            named[color]
+                        ),
+                    );
+                }
+            }
+        }
+    }
+    report.parse_errors(games.errors());
+    report.check_numbering("game", game_nums);
+    report.errors.sort_by_key(|issue| issue.line);
+    report.warnings.sort_by_key(|issue| issue.line);
+    report
+}
+
+mod test {
+
+    #[test]
+    fn example_is_fine() {
+        use crate::validate::validate;
+
+        let input = include_str!("../examples/1.txt");
+        assert!(validate(input).is_empty(), "{}", validate(input));
+    }
+
+    #[test]
+    fn provided_input_is_fine() {
+        use crate::validate::validate;
+
+        let input = aoc::input!();
+        assert!(validate(&input).is_empty(), "{}", validate(&input));
+    }
+
+    #[test]
+    fn reports_odd_games() {
+        use aoc::input::Input;
+
+        use crate::validate::validate;
+
+        let input = Input::literal(
+            "
+            Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+            Game 3: 1 blue, 2 blue; 0 green
+            Game 3: 8 green
+            Game 4: 1 green, 3 purple
+            Game 2: 6 red, 1 blue; 2 red, 1 red, 2 red
+            ",
+        );
+        insta::assert_snapshot!(validate(&input).to_string(), @"
+        error: line 4: column 16: `, 3 purple` is left over
+        warning: line 2: hand 1 says how many blue it has 2 times
+        warning: line 2: hand 2 has 0 green
+        warning: line 3: game 3 is already on line 2
+        warning: line 5: hand 2 says how many red it has 3 times
+        warning: line 5: game 2 comes after game 3
+        ");
+    }
+}
@@ -1,25 +0,0 @@
-fn main() {
-    todo!()
-}
-
-pub fn part_one(_input: &str) -> usize {
-    todo!()
-}
-
-mod test {
-    #[test]
-    fn test_example() {
-        use crate::part_one;
-        let input = r#"467..114..
-        ...*......
-        ..35..633.
-        ......#...
-        617*......
-        .....+.58.
-        ..592.....
-        ......755.
-        ...$.*....
-        .664.598.."#;
-        assert_eq!(part_one(input), 4361);
-    }
-}
@@ -0,0 +1,190 @@
+use grid::{Grid, Point};
+
+pub mod part_one;
+pub mod render;
+
+aoc_macros::example_tests! {
+    part_one: crate::part_one::part_one,
+}
+
+/// What a single cell of the schematic turned out to be.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Classification {
+    /// Part of a number adjacent to a symbol.
+    PartNumber,
+    /// Part of a number with no symbol around it.
+    NonPartNumber,
+    Symbol,
+    /// A `*` adjacent to exactly two part numbers.
+    Gear,
+    Empty,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Number {
+    pub row: usize,
+    /// The first column of the number.
+    pub start: usize,
+    /// One past the last column of the number.
+    pub end: usize,
+    pub value: usize,
+}
+
+impl Number {
+    /// Whether the cell is next to (diagonals included) or on the number.
+    pub fn is_adjacent(&self, row: usize, col: usize) -> bool {
+        row + 1 >= self.row && row <= self.row + 1 && col + 1 >= self.start && col <= self.end
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Symbol {
+    pub row: usize,
+    pub col: usize,
+    pub symbol: char,
+}
+
+#[derive(Debug)]
+pub struct Schematic<'a> {
+    pub lines: Vec<&'a str>,
+    pub numbers: Vec<Number>,
+    pub symbols: Vec<Symbol>,
+    classifications: Grid<Classification>,
+}
+
+impl<'a> Schematic<'a> {
+    /// Finds every number and symbol, then classifies each cell by what's around it.
+    pub fn parse(input: &'a str) -> Self {
+        let lines = input.lines().map(str::trim).collect::<Vec<_>>();
+
+        let mut numbers = vec![];
+        let mut symbols = vec![];
+        for (row, line) in lines.iter().enumerate() {
+            let mut current: Option<Number> = None;
+            for (col, c) in line.chars().enumerate() {
+                match c.to_digit(10) {
+                    Some(digit) => {
+                        let number = current.get_or_insert(Number {
+                            row,
+                            start: col,
+                            end: col,
+                            value: 0,
+                        });
+                        number.end = col + 1;
+                        number.value = number.value * 10 + digit as usize;
+                    }
+                    None => {
+                        numbers.extend(current.take());
+                        if c != '.' {
+                            symbols.push(Symbol {
+                                row,
+                                col,
+                                symbol: c,
+                            });
+                        }
+                    }
+                }
+            }
+            numbers.extend(current);
+        }
+
+        let mut schematic = Self {
+            classifications: Grid::new(
+                lines
+                    .iter()
+                    .map(|line| line.chars().count())
+                    .max()
+                    .unwrap_or(0),
+                lines.len(),
+                Classification::Empty,
+            ),
+            lines,
+            numbers,
+            symbols,
+        };
+        schematic.classify();
+        schematic
+    }
+
+    fn classify(&mut self) {
+        for symbol in &self.symbols {
+            let classification = if self.is_gear(symbol) {
+                Classification::Gear
+            } else {
+                Classification::Symbol
+            };
+            self.classifications[point(symbol.row, symbol.col)] = classification;
+        }
+        for number in &self.numbers {
+            let classification = if self.is_part_number(number) {
+                Classification::PartNumber
+            } else {
+                Classification::NonPartNumber
+            };
+            for col in number.start..number.end {
+                self.classifications[point(number.row, col)] = classification;
+            }
+        }
+    }
+
+    pub fn is_part_number(&self, number: &Number) -> bool {
+        self.symbols
+            .iter()
+            .any(|symbol| number.is_adjacent(symbol.row, symbol.col))
+    }
+
+    pub fn part_numbers(&self) -> impl Iterator<Item = &Number> {
+        self.numbers
+            .iter()
+            .filter(|number| self.is_part_number(number))
+    }
+
+    pub fn adjacent_numbers<'s>(&'s self, symbol: &'s Symbol) -> impl Iterator<Item = &'s Number> {
+        self.numbers
+            .iter()
+            .filter(|number| number.is_adjacent(symbol.row, symbol.col))
+    }
+
+    pub fn is_gear(&self, symbol: &Symbol) -> bool {
+        symbol.symbol == '*' && self.adjacent_numbers(symbol).count() == 2
+    }
+
+    /// What the cell at `row`, `col` is, or `None` if it's off the schematic.
+    pub fn classification(&self, row: usize, col: usize) -> Option<Classification> {
+        self.classifications.get(point(row, col)).copied()
+    }
+
+    pub fn height(&self) -> usize {
+        self.lines.len()
+    }
+}
+
+fn point(row: usize, col: usize) -> Point {
+    Point::new(col as isize, row as isize)
+}
+
+mod test {
+    #[test]
+    fn classifies_provided_example() {
+        use crate::{Classification, Schematic};
+        let input = include_str!("../examples/1.txt");
+        let schematic = Schematic::parse(input);
+
+        assert_eq!(
+            schematic.classification(0, 0),
+            Some(Classification::PartNumber)
+        );
+        assert_eq!(
+            schematic.classification(0, 5),
+            Some(Classification::NonPartNumber)
+        );
+        assert_eq!(schematic.classification(1, 3), Some(Classification::Gear));
+        assert_eq!(schematic.classification(4, 3), Some(Classification::Symbol));
+        assert_eq!(
+            schematic.classification(5, 7),
+            Some(Classification::NonPartNumber)
+        );
+        assert_eq!(schematic.classification(0, 3), Some(Classification::Empty));
+        assert_eq!(schematic.classification(10, 0), None);
+    }
+}
@@ -1,3 +1,31 @@
+use day_three::{
+    render::{render, Window},
+    Schematic,
+};
+
+/// Prints a schematic with its part numbers, symbols and gears highlighted.
+///
+/// usage: day-three <schematic> [<row> <col> [<radius>]]
 fn main() {
-    println!("Hello, world!");
+    let mut args = std::env::args().skip(1);
+    let path = args
+        .next()
+        .expect("usage: day-three <schematic> [<row> <col> [<radius>]]");
+    let input = std::fs::read_to_string(path).unwrap();
+
+    let coordinates = args
+        .map(|arg| arg.parse::<usize>().unwrap())
+        .collect::<Vec<_>>();
+    let window = match coordinates[..] {
+        [] => None,
+        [row, col] => Some(Window {
+            row,
+            col,
+            radius: 5,
+        }),
+        [row, col, radius] => Some(Window { row, col, radius }),
+        _ => panic!("usage: day-three <schematic> [<row> <col> [<radius>]]"),
+    };
+
+    print!("{}", render(&Schematic::parse(&input), window));
 }
@@ -0,0 +1,13 @@
+use aoc::answer::{Accumulate, Answer, Overflow};
+use aoc_macros::aoc;
+
+use crate::Schematic;
+
+/// Sum up every number with a symbol next to it.
+#[aoc(2023, 3, 1)]
+pub fn part_one(input: &str) -> Result<Answer, Overflow> {
+    Schematic::parse(input)
+        .part_numbers()
+        .map(|number| number.value)
+        .sum_answers()
+}
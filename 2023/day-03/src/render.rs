@@ -0,0 +1,128 @@
+use std::fmt::Write;
+
+use crate::{Classification, Schematic};
+
+const RESET: &str = "\x1b[0m";
+const UNDERLINE: &str = "\x1b[4m";
+
+impl Classification {
+    /// The ANSI escape we color this kind of cell with.
+    fn color(&self) -> &'static str {
+        match self {
+            Classification::PartNumber => "\x1b[1;32m",
+            Classification::NonPartNumber => "\x1b[31m",
+            Classification::Symbol => "\x1b[33m",
+            Classification::Gear => "\x1b[1;35m",
+            Classification::Empty => "\x1b[2m",
+        }
+    }
+}
+
+/// Only render the cells within `radius` of `row`, `col`, which gets underlined.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Window {
+    pub row: usize,
+    pub col: usize,
+    pub radius: usize,
+}
+
+impl Window {
+    fn rows(&self) -> std::ops::RangeInclusive<usize> {
+        self.row.saturating_sub(self.radius)..=self.row + self.radius
+    }
+
+    fn cols(&self) -> std::ops::RangeInclusive<usize> {
+        self.col.saturating_sub(self.radius)..=self.col + self.radius
+    }
+}
+
+/// Renders the schematic with ANSI colors: green part numbers, red numbers that aren't parts,
+/// yellow symbols and magenta gears, with the row number in a gutter on the left.
+///
+/// ```
+/// use day_three::{render::render, Schematic};
+/// let schematic = Schematic::parse("467..\n...*.");
+/// assert_eq!(
+///     render(&schematic, None),
+///     "0 │ \x1b[1;32m467\x1b[2m..\x1b[0m\n1 │ \x1b[2m...\x1b[33m*\x1b[2m.\x1b[0m\n",
+/// );
+/// ```
+pub fn render(schematic: &Schematic, window: Option<Window>) -> String {
+    let rows = window.map_or(0..=usize::MAX, |window| window.rows());
+    let cols = window.map_or(0..=usize::MAX, |window| window.cols());
+    let gutter = schematic.height().saturating_sub(1).to_string().len();
+
+    let mut output = String::new();
+    for (row, line) in schematic
+        .lines
+        .iter()
+        .enumerate()
+        .filter(|(row, _)| rows.contains(row))
+    {
+        write!(output, "{row:>gutter$} │ ").unwrap();
+
+        let mut current_color = None;
+        for (col, c) in line
+            .chars()
+            .enumerate()
+            .filter(|(col, _)| cols.contains(col))
+        {
+            let color = schematic
+                .classification(row, col)
+                .unwrap_or(Classification::Empty)
+                .color();
+            let is_center = window.is_some_and(|window| window.row == row && window.col == col);
+
+            if is_center {
+                write!(output, "{color}{UNDERLINE}{c}{RESET}").unwrap();
+                current_color = None;
+                continue;
+            }
+            if current_color != Some(color) {
+                output.push_str(color);
+                current_color = Some(color);
+            }
+            output.push(c);
+        }
+
+        output.push_str(RESET);
+        output.push('\n');
+    }
+
+    output
+}
+
+mod test {
+    #[test]
+    fn snapshot_provided_example() {
+        use crate::render::render;
+        use crate::Schematic;
+
+        let input = include_str!("../examples/1.txt");
+
+        insta::assert_debug_snapshot!(render(&Schematic::parse(input), None));
+    }
+
+    #[test]
+    fn snapshot_window_is_clamped_to_the_schematic() {
+        use crate::render::{render, Window};
+        use crate::Schematic;
+
+        let input = include_str!("../examples/1.txt");
+        let schematic = Schematic::parse(input);
+
+        let window = Window {
+            row: 1,
+            col: 3,
+            radius: 2,
+        };
+        insta::assert_debug_snapshot!(render(&schematic, Some(window)));
+
+        let window = Window {
+            row: 9,
+            col: 9,
+            radius: 1,
+        };
+        insta::assert_debug_snapshot!(render(&schematic, Some(window)));
+    }
+}
@@ -0,0 +1,60 @@
+use aoc::{input::Input, parse::Parse};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use day_4::{part_one::part_one, part_two::part_two, ScratchoffCard};
+
+fn cards(input: &Input) -> Vec<ScratchoffCard> {
+    input
+        .lines()
+        .map(|line| ScratchoffCard::parse(line).unwrap().1)
+        .collect()
+}
+
+fn matching(c: &mut Criterion) {
+    let cards = cards(&aoc::input!());
+    let mut group = c.benchmark_group("matching");
+
+    group.bench_function("bitset", |b| {
+        b.iter(|| {
+            black_box(&cards)
+                .iter()
+                .map(ScratchoffCard::matches)
+                .sum::<usize>()
+        })
+    });
+
+    // What we did before the bitset: scan every winning number for each of mine.
+    let vecs = cards
+        .iter()
+        .map(|card| {
+            (
+                card.winning_numbers.iter().collect::<Vec<_>>(),
+                card.my_numbers.iter().collect::<Vec<_>>(),
+            )
+        })
+        .collect::<Vec<_>>();
+    group.bench_function("vec_scan", |b| {
+        b.iter(|| {
+            black_box(&vecs)
+                .iter()
+                .map(|(winning_numbers, my_numbers)| {
+                    my_numbers
+                        .iter()
+                        .filter(|num| winning_numbers.iter().any(|winner| winner == *num))
+                        .count()
+                })
+                .sum::<usize>()
+        })
+    });
+
+    group.finish();
+}
+
+fn solutions(c: &mut Criterion) {
+    let input = aoc::input!();
+    c.bench_function("parse", |b| b.iter(|| cards(black_box(&input))));
+    c.bench_function("part_one", |b| b.iter(|| part_one(black_box(&input))));
+    c.bench_function("part_two", |b| b.iter(|| part_two(black_box(&input))));
+}
+
+criterion_group!(benches, matching, solutions);
+criterion_main!(benches);
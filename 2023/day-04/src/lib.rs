@@ -1,2 +1,160 @@
+use aoc::{
+    answer::{Answer, Overflow},
+    parse::Parse,
+};
+use nom::{
+    bytes::complete::tag, combinator::verify, multi::many0_count, sequence::terminated, IResult,
+};
+
 pub mod part_one;
 pub mod part_two;
+pub mod validate;
+pub mod what_if;
+
+aoc_macros::example_tests! {
+    part_one: crate::part_one::part_one,
+    part_two: crate::part_two::part_two,
+}
+
+/// The numbers are right-aligned in columns, which parsing numbers skipping the spaces in front of
+/// them takes care of. Spaces left after the last number are skipped too.
+#[derive(Debug, PartialEq, Clone, Copy, Parse)]
+#[format("Card {card_num}: {winning_numbers} | {my_numbers}")]
+pub struct ScratchoffCard {
+    pub card_num: usize,
+    #[format(sep = " ", with = lottery_number)]
+    pub winning_numbers: LotteryNums,
+    #[format(with = parse_lottery_numbers)]
+    pub my_numbers: LotteryNums,
+}
+
+impl ScratchoffCard {
+    /// How many of my numbers are winning numbers.
+    pub fn matches(&self) -> usize {
+        self.winning_numbers.matches(&self.my_numbers)
+    }
+
+    /// The first correct number scores 1 point, then each after that doubles the score.
+    pub fn score(&self) -> Result<Answer, Overflow> {
+        Self::calc_score(self.matches())
+    }
+
+    /// A card can match up to 128 numbers, which scores a `u128`'s top bit, so anything past that
+    /// overflows.
+    ///
+    /// ```
+    /// use aoc::answer::Answer;
+    /// use day_4::ScratchoffCard;
+    /// assert_eq!(ScratchoffCard::calc_score(0).unwrap(), 0);
+    /// assert_eq!(ScratchoffCard::calc_score(1).unwrap(), 1);
+    /// assert_eq!(ScratchoffCard::calc_score(2).unwrap(), 2);
+    /// assert_eq!(ScratchoffCard::calc_score(3).unwrap(), 4);
+    /// assert_eq!(ScratchoffCard::calc_score(4).unwrap(), 8);
+    /// assert_eq!(ScratchoffCard::calc_score(128).unwrap(), Answer::from(1u128 << 127));
+    /// assert!(ScratchoffCard::calc_score(129).is_err());
+    /// ```
+    pub fn calc_score(winning_numbers: usize) -> Result<Answer, Overflow> {
+        match winning_numbers.checked_sub(1) {
+            None => Ok(Answer::ZERO),
+            Some(doublings) => {
+                (0..doublings).try_fold(Answer::from(1u64), |score, _| score.checked_mul(2u64))
+            }
+        }
+    }
+}
+
+/// A set of lottery numbers, one bit per number, so matching two cards is an `&` and a popcount.
+///
+/// Every number on the cards fits in 0..100, comfortably under the 128 bits we have room for.
+#[derive(Default, PartialEq, Eq, Hash, Clone, Copy)]
+pub struct LotteryNums(u128);
+
+impl LotteryNums {
+    /// The numbers are stored as bits, so anything past this doesn't fit.
+    pub const MAX: usize = u128::BITS as usize - 1;
+
+    /// ```
+    /// use day_4::LotteryNums;
+    /// let mut nums = LotteryNums::default();
+    /// nums.insert(69);
+    /// assert!(nums.contains(69));
+    /// assert!(!nums.contains(11));
+    /// ```
+    pub fn insert(&mut self, num: usize) {
+        assert!(num <= Self::MAX, "{num} doesn't fit in LotteryNums");
+        self.0 |= 1 << num;
+    }
+
+    pub fn contains(&self, num: usize) -> bool {
+        num <= Self::MAX && self.0 & (1 << num) != 0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// How many numbers are in both sets.
+    ///
+    /// ```
+    /// use day_4::LotteryNums;
+    /// let winning_numbers = LotteryNums::from_iter([41, 48, 83, 86, 17]);
+    /// let my_numbers = LotteryNums::from_iter([83, 86, 6, 31, 17, 9, 48, 53]);
+    /// assert_eq!(winning_numbers.matches(&my_numbers), 4);
+    /// ```
+    pub fn matches(&self, other: &LotteryNums) -> usize {
+        (self.0 & other.0).count_ones() as usize
+    }
+
+    /// The numbers in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = usize> {
+        let bits = self.0;
+        (0..=Self::MAX).filter(move |num| bits & (1 << num) != 0)
+    }
+}
+
+impl FromIterator<usize> for LotteryNums {
+    fn from_iter<T: IntoIterator<Item = usize>>(iter: T) -> Self {
+        let mut nums = Self::default();
+        nums.extend(iter);
+        nums
+    }
+}
+
+impl Extend<usize> for LotteryNums {
+    fn extend<T: IntoIterator<Item = usize>>(&mut self, iter: T) {
+        for num in iter {
+            self.insert(num);
+        }
+    }
+}
+
+impl std::fmt::Debug for LotteryNums {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// A number that fits in a [`LotteryNums`], so one that doesn't is a parse error rather than a
+/// panic.
+fn lottery_number(input: &str) -> IResult<&str, usize> {
+    verify(usize::parse, |num| *num <= LotteryNums::MAX)(input)
+}
+
+/// Numbers with spaces between them, and any spaces after the last one.
+///
+/// ```
+/// use day_4::{parse_lottery_numbers, LotteryNums};
+/// let input = " 1 2 3 14 69 11";
+/// assert_eq!(parse_lottery_numbers(input).unwrap(), ("", LotteryNums::from_iter([1, 2, 3, 14, 69, 11])));
+/// assert_eq!(parse_lottery_numbers("1 2  | 3").unwrap(), ("| 3", LotteryNums::from_iter([1, 2])));
+/// ```
+pub fn parse_lottery_numbers(input: &str) -> IResult<&str, LotteryNums> {
+    terminated(
+        aoc::parse::separated(lottery_number, " "),
+        many0_count(tag(" ")),
+    )(input)
+}
@@ -1,129 +1,109 @@
-use nom::{
-    bytes::complete::tag,
-    character::complete::digit1,
-    combinator::map_res,
-    multi::{many0, many1, separated_list1},
-    sequence::tuple,
-    IResult,
+use aoc::{
+    answer::Answer,
+    explain::Explanation,
+    parse::{parse_lines, Mode},
+    solution::SolveError,
 };
-
-fn digit_parser(input: &str) -> IResult<&str, usize> {
-    map_res(digit1, str::parse)(input)
+use aoc_macros::aoc;
+
+use crate::ScratchoffCard;
+
+#[aoc(2023, 4, 1, explain = explain_part_one)]
+pub fn part_one(input: &str) -> Result<Answer, SolveError> {
+    let mut cards = parse_lines::<ScratchoffCard>(input, Mode::Strict);
+    let total = cards.by_ref().try_fold(Answer::ZERO, |total, (_, card)| {
+        total.checked_add(card.score()?)
+    })?;
+    cards.finish()?;
+    Ok(total)
 }
 
-#[derive(Debug)]
-pub struct ScratchoffCard {
-    card_num: usize,
-    /// The first correct number scores 1 point, then each after that doubles the score.
-    score: usize,
-    winning_numbers: LotteryNums,
-    my_numbers: LotteryNums,
+/// How many numbers each card matched, and what that scored.
+pub fn explain_part_one(input: &str) -> Explanation {
+    let mut explanation = Explanation::new(&["card", "matches"]);
+    let cards = parse_lines::<ScratchoffCard>(input, Mode::Strict).collect_all();
+    for (line, card) in cards.unwrap_or_else(|errors| panic!("{errors}")) {
+        let score = card.score().unwrap_or_else(|overflow| {
+            panic!("card {}'s score overflowed: {overflow}", card.card_num)
+        });
+        explanation.push(
+            line,
+            vec![card.card_num.to_string(), card.matches().to_string()],
+            score,
+        );
+    }
+    explanation
 }
 
-impl ScratchoffCard {
-    fn parse(input: &str) -> IResult<&str, Self> {
-        let (input, _) = tuple((many0(tag(" ")), tag("Card"), many1(tag(" "))))(input)?;
-        let (input, card_num) = digit_parser(input)?;
-        let (input, _) = tag(": ")(input)?;
-        let (input, lottery_numbers) = separated_list1(
-            tuple((many0(tag(" ")), tag("|"), many0(tag(" ")))),
-            parse_lottery_numbers,
-        )(input)?;
-
-        assert_eq!(lottery_numbers.len(), 2);
-
-        let winning_numbers = lottery_numbers[0].clone();
-        let my_numbers = lottery_numbers[1].clone();
-
-        let my_winning_numbers_count = my_numbers
-            .iter()
-            .filter(|num| winning_numbers.iter().any(|winner| winner == *num))
-            .count();
-
-        let score = Self::calc_score(my_winning_numbers_count);
+mod test {
 
-        Ok((
-            input,
-            Self {
-                card_num,
-                winning_numbers,
-                my_numbers,
-                score,
-            },
-        ))
+    #[test]
+    fn provided_input() {
+        use crate::part_one::part_one;
+        let input = aoc::input!();
+        assert_eq!(part_one(&input).unwrap(), 25004usize);
     }
 
-    /// ```
-    /// use day_4::part_one::ScratchoffCard;
-    /// assert_eq!(ScratchoffCard::calc_score(0), 0);
-    /// assert_eq!(ScratchoffCard::calc_score(1), 1);
-    /// assert_eq!(ScratchoffCard::calc_score(2), 2);
-    /// assert_eq!(ScratchoffCard::calc_score(3), 4);
-    /// assert_eq!(ScratchoffCard::calc_score(4), 8);
-    /// ```
-    pub fn calc_score(winning_numbers: usize) -> usize {
-        if winning_numbers == 0 {
-            return 0;
-        }
-        if winning_numbers == 1 {
-            return 1;
-        }
-        let mut score = 1;
-        // Double the score for every additional winning number.
-        for _ in 1..winning_numbers {
-            score *= 2;
-        }
+    #[test]
+    fn full_input_does_not_allocate() {
+        use alloc_counter::allocations_during;
 
-        score
+        use crate::part_one::part_one;
+        let input = aoc::input!();
+        let (answer, allocations) = allocations_during(|| part_one(&input));
+        assert_eq!((answer.unwrap(), allocations), (25004usize.into(), 0));
     }
-}
 
-type LotteryNums = Vec<usize>;
+    /// A card that doesn't parse used to be left out of the sum.
+    #[test]
+    fn bad_cards_are_not_skipped() {
+        use crate::part_one::part_one;
+        let error = part_one("Card 1: 1 2 | 1 2\nCard 2: x 2 | 1 2\nCard 3: 1 2 | 1 2");
+        insta::assert_snapshot!(error.unwrap_err().to_string(), @"
+        1 line of the input didn't parse
+        line 2 column 9: couldn't parse `x 2 | 1 2`
+        ");
+    }
 
-/// ```
-/// use day_4::part_one::parse_lottery_numbers;
-/// let input = " 1 2 3 14 69 11";
-/// assert_eq!(parse_lottery_numbers(input).unwrap(), ("", vec![1, 2, 3, 14, 69, 11]));
-/// ```
-pub fn parse_lottery_numbers(input: &str) -> IResult<&str, LotteryNums> {
-    let (input, (_, nums, _)) = tuple((
-        many0(tag(" ")),
-        separated_list1(many1(tag(" ")), digit_parser),
-        many0(tag(" ")),
-    ))(input)?;
+    /// A card matching all 128 numbers scores 2^127, so two of them overflow a `u128`.
+    #[test]
+    fn scores_that_overflow_are_errors() {
+        use aoc::answer::Answer;
 
-    Ok((input, nums))
-}
+        use crate::part_one::part_one;
 
-pub fn part_one(input: &str) -> usize {
-    input
-        .lines()
-        .flat_map(|line| {
-            ScratchoffCard::parse(line).map(|(input, card)| {
-                assert!(input.is_empty());
-                card.score
-            })
-        })
-        .sum()
-}
+        let numbers = (0..=127)
+            .map(|num| num.to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let card = |card_num| format!("Card {card_num}: {numbers} | {numbers}");
+        assert_eq!(part_one(&card(1)).unwrap(), Answer::from(1u128 << 127));
+        assert!(part_one(&format!("{}\n{}", card(1), card(2))).is_err());
+    }
 
-mod test {
     #[test]
-    fn provided_testcase() {
+    fn trailing_spaces_are_fine() {
         use crate::part_one::part_one;
-        let input = r#"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
-        Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
-        Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
-        Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
-        Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
-        Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11"#;
-        assert_eq!(part_one(input), 13);
+        let input = "Card 1: 1 2 | 1 2  \nCard 2: 3 4 | 3 5 ";
+        assert_eq!(part_one(input).unwrap(), 3usize);
     }
 
     #[test]
-    fn provided_input() {
-        use crate::part_one::part_one;
-        let input = include_str!("./input.txt");
-        assert_eq!(part_one(input), 25004);
+    fn explain_provided_testcase() {
+        use crate::part_one::explain_part_one;
+        let input = include_str!("../examples/1.txt");
+        let explanation = explain_part_one(input);
+        assert_eq!(explanation.total().unwrap(), 13);
+        insta::assert_snapshot!(explanation.to_string(), @"
+        line │ card │ matches │ contribution
+        ─────┼──────┼─────────┼─────────────
+        1    │ 1    │ 4       │ 8
+        2    │ 2    │ 2       │ 2
+        3    │ 3    │ 2       │ 2
+        4    │ 4    │ 1       │ 1
+        5    │ 5    │ 0       │ 0
+        6    │ 6    │ 0       │ 0
+        total: 13
+        ");
     }
 }
@@ -1,100 +1,157 @@
-use nom::{
-    bytes::complete::tag,
-    character::complete::digit1,
-    combinator::map_res,
-    multi::{many0, many1, separated_list1},
-    sequence::tuple,
-    IResult,
+use aoc::{
+    answer::{checked_add, Answer, Overflow},
+    explain::Explanation,
+    parse::{parse_lines, Mode},
+    solution::SolveError,
 };
+use aoc_macros::aoc;
 
-fn digit_parser(input: &str) -> IResult<&str, usize> {
-    map_res(digit1, str::parse)(input)
-}
-
-#[derive(Debug)]
-pub struct ScratchoffCard {
-    card_num: usize,
-    winning_numbers: LotteryNums,
-    my_numbers: LotteryNums,
-}
+use crate::{LotteryNums, ScratchoffCard};
 
-impl ScratchoffCard {
-    fn parse(input: &str) -> IResult<&str, Self> {
-        let (input, _) = tuple((many0(tag(" ")), tag("Card"), many1(tag(" "))))(input)?;
-        let (input, card_num) = digit_parser(input)?;
-        let (input, _) = tag(": ")(input)?;
-        let (input, lottery_numbers) = separated_list1(
-            tuple((many0(tag(" ")), tag("|"), many0(tag(" ")))),
-            parse_lottery_numbers,
-        )(input)?;
+/// Each card wins a copy of the next `matches` cards, and copies win copies of their own.
+///
+/// Since a card only ever hands out copies to the cards right after it, we stream through them keeping
+/// the copies owed to the upcoming cards in a ring, which can't be outrun as a card can't match more
+/// numbers than a `LotteryNums` holds.
+///
+/// The copies can double with every card, so they're counted in `u128`s and checked, and it stops
+/// at the first card whose copies overflow.
+pub(crate) fn cascade(
+    cards: impl Iterator<Item = ScratchoffCard>,
+) -> impl Iterator<Item = Result<(ScratchoffCard, u128), Overflow>> {
+    const RING: usize = LotteryNums::MAX + 1;
 
-        assert_eq!(lottery_numbers.len(), 2);
-
-        let winning_numbers = lottery_numbers[0].clone();
-        let my_numbers = lottery_numbers[1].clone();
+    let hand_out = |owed_copies: &mut [u128; RING], index: usize, card: &ScratchoffCard| {
+        let copies = checked_add(1, std::mem::take(&mut owed_copies[index % RING]))?;
+        for won in 1..=card.matches() {
+            let owed = &mut owed_copies[(index + won) % RING];
+            *owed = checked_add(*owed, copies)?;
+        }
+        Ok(copies)
+    };
+    cards
+        .enumerate()
+        .scan(Some([0; RING]), move |owed_copies, (index, card)| {
+            let copies = hand_out(owed_copies.as_mut()?, index, &card);
+            if copies.is_err() {
+                *owed_copies = None;
+            }
+            Some(copies.map(|copies| (card, copies)))
+        })
+}
 
-        let my_winning_numbers_count = my_numbers
-            .iter()
-            .filter(|num| winning_numbers.iter().any(|winner| winner == *num))
-            .count();
+#[aoc(2023, 4, 2, explain = explain_part_two)]
+pub fn part_two(input: &str) -> Result<Answer, SolveError> {
+    let mut cards = parse_lines::<ScratchoffCard>(input, Mode::Strict);
+    let total = cascade(cards.by_ref().map(|(_, card)| card))
+        .try_fold(Answer::ZERO, |total, card| total.checked_add(card?.1))?;
+    cards.finish()?;
+    Ok(total)
+}
 
-        Ok((
-            input,
-            Self {
-                card_num,
-                winning_numbers,
-                my_numbers,
-            },
-        ))
+/// How many copies of each card we ended up with.
+pub fn explain_part_two(input: &str) -> Explanation {
+    let mut explanation = Explanation::new(&["card", "matches"]);
+    let cards = parse_lines::<ScratchoffCard>(input, Mode::Strict).collect_all();
+    let cards = cards.unwrap_or_else(|errors| panic!("{errors}"));
+    let lines = cards.iter().map(|&(line, _)| line);
+    for (line, card) in lines.zip(cascade(cards.iter().map(|&(_, card)| card))) {
+        let (card, copies) =
+            card.unwrap_or_else(|overflow| panic!("line {line}'s copies overflowed: {overflow}"));
+        explanation.push(
+            line,
+            vec![card.card_num.to_string(), card.matches().to_string()],
+            copies,
+        );
     }
+    explanation
 }
 
-type LotteryNums = Vec<usize>;
+mod test {
 
-/// ```
-/// use day_4::part_one::parse_lottery_numbers;
-/// let input = " 1 2 3 14 69 11";
-/// assert_eq!(parse_lottery_numbers(input).unwrap(), ("", vec![1, 2, 3, 14, 69, 11]));
-/// ```
-pub fn parse_lottery_numbers(input: &str) -> IResult<&str, LotteryNums> {
-    let (input, (_, nums, _)) = tuple((
-        many0(tag(" ")),
-        separated_list1(many1(tag(" ")), digit_parser),
-        many0(tag(" ")),
-    ))(input)?;
+    #[test]
+    fn provided_input() {
+        use crate::part_two::part_two;
+        let input = aoc::input!();
+        assert_eq!(part_two(&input).unwrap(), 14427616usize);
+    }
 
-    Ok((input, nums))
-}
+    #[test]
+    fn full_input_does_not_allocate() {
+        use alloc_counter::allocations_during;
 
-pub fn part_two(input: &str) -> usize {
-    let initial_cards = input
-        .lines()
-        .map(|line| ScratchoffCard::parse(line).unwrap().1)
-        .collect::<Vec<_>>();
+        use crate::part_two::part_two;
+        let input = aoc::input!();
+        let (answer, allocations) = allocations_during(|| part_two(&input));
+        assert_eq!((answer.unwrap(), allocations), (14427616usize.into(), 0));
+    }
 
-    dbg!(initial_cards);
+    /// The copying is a DAG of cards winning later cards, so ordering it and pushing copies along the
+    /// edges has to give the same answer as the ring.
+    #[test]
+    fn cascade_agrees_with_topological_order() {
+        use crate::part_two::part_two;
+        use crate::ScratchoffCard;
+        use aoc::parse::Parse;
+        use graph::{topological_sort, AdjacencyList, Graph};
 
-    todo!();
-}
+        let input = aoc::input!();
+        let cards = input
+            .lines()
+            .map(|line| ScratchoffCard::parse(line).unwrap().1)
+            .collect::<Vec<_>>();
+        let mut wins = AdjacencyList::new();
+        for (index, card) in cards.iter().enumerate() {
+            wins.add_node(index);
+            for won in index + 1..=index + card.matches() {
+                wins.add_edge(index, won, 1);
+            }
+        }
 
-mod test {
+        let order = topological_sort(&wins, 0..cards.len()).unwrap();
+        let mut copies = vec![1; cards.len()];
+        for card in order {
+            for (won, _) in wins.edges(&card) {
+                copies[won] += copies[card];
+            }
+        }
+        assert_eq!(part_two(&input).unwrap(), copies.iter().sum::<usize>());
+    }
+
+    /// Every card winning the next ten makes the copies grow about as fast as doubling, so 200 of
+    /// them is well past a `u128`.
     #[test]
-    fn provided_testcase() {
+    fn copies_that_overflow_are_errors() {
         use crate::part_two::part_two;
-        let input = r#"Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
-        Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
-        Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
-        Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
-        Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
-        Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11"#;
-        // 30 scratchcards
-        assert_eq!(part_two(input), 30);
+
+        let numbers = (1..=10)
+            .map(|num| num.to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let input = (1..=200)
+            .map(|card_num| format!("Card {card_num}: {numbers} | {numbers}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert!(part_two(&input).is_err());
+        assert!(part_two(&input.lines().take(50).collect::<Vec<_>>().join("\n")).is_ok());
     }
 
     #[test]
-    fn provided_input() {
-        use crate::part_two::part_two;
-        let input = include_str!("./input.txt");
-        assert_eq!(part_two(input), 0);
+    fn explain_provided_testcase() {
+        use crate::part_two::explain_part_two;
+        let input = include_str!("../examples/1.txt");
+        let explanation = explain_part_two(input);
+        assert_eq!(explanation.total().unwrap(), 30);
+        insta::assert_snapshot!(explanation.to_string(), @"
+        line │ card │ matches │ contribution
+        ─────┼──────┼─────────┼─────────────
+        1    │ 1    │ 4       │ 1
+        2    │ 2    │ 2       │ 2
+        3    │ 3    │ 2       │ 4
+        4    │ 4    │ 1       │ 8
+        5    │ 5    │ 0       │ 14
+        6    │ 6    │ 0       │ 1
+        total: 30
+        ");
     }
 }
@@ -0,0 +1,151 @@
+//! What the input should look like beyond each line being a card: the cards numbered 1, 2, 3 and
+//! so on, every card with as many winning numbers and numbers of its own as the others, none of
+//! them twice on a card, and all of them from 1 to 99.
+//!
+//! A [`LotteryNums`] is a set, so a number that's on a card twice is only counted once, which is
+//! why the numbers are counted from the text as well.
+//!
+//! [`LotteryNums`]: crate::LotteryNums
+
+use aoc::{
+    parse::{parse_lines, Mode},
+    validate::Report,
+};
+use aoc_macros::aoc_validator;
+
+use crate::ScratchoffCard;
+
+/// The numbers the cards use.
+const NUMBERS: std::ops::RangeInclusive<usize> = 1..=99;
+
+/// The numbers in one side of a card, as written.
+fn written_numbers(text: &str) -> Vec<usize> {
+    text.split_whitespace()
+        .filter_map(|number| number.parse().ok())
+        .collect()
+}
+
+#[aoc_validator(2023, 4)]
+pub fn validate(input: &str) -> Report {
+    let mut report = Report::default();
+    let mut card_nums = vec![];
+    let mut cards = vec![];
+    let texts = input.lines().collect::<Vec<_>>();
+    let mut parsed = parse_lines::<ScratchoffCard>(input, Mode::Lenient);
+    for (line, card) in parsed.by_ref() {
+        card_nums.push((line, card.card_num));
+
+        // It parsed, so it has a colon and a bar.
+        let (_, numbers) = texts[line - 1].split_once(':').unwrap();
+        let (winning, mine) = numbers.split_once('|').unwrap();
+        let sides = [
+            ("winning numbers", written_numbers(winning)),
+            ("numbers of its own", written_numbers(mine)),
+        ];
+        for (side, numbers) in &sides {
+            for (position, number) in numbers.iter().enumerate() {
+                if numbers[..position].contains(number) {
+                    report.warn(line, format!("{number} is in the {side} twice"));
+                }
+                if !NUMBERS.contains(number) {
+                    report.warn(
+                        line,
+                        format!(
+                            "{number} isn't from {} to {}",
+                            NUMBERS.start(),
+                            NUMBERS.end()
+                        ),
+                    );
+                }
+            }
+        }
+        cards.push((line, card, sides.map(|(_, numbers)| numbers.len())));
+    }
+
+    if let Some(&(_, first, widths)) = cards.first() {
+        for &(line, card, card_widths) in &cards[1..] {
+            let sides = ["winning numbers", "numbers of its own"];
+            for ((side, width), first_width) in sides.iter().zip(card_widths).zip(widths) {
+                if width != first_width {
+                    report.warn(
+                        line,
+                        format!(
+                            "card {} has {width} {side}, card {} has {first_width}",
+                            card.card_num, first.card_num
+                        ),
+                    );
+                }
+            }
+        }
+    }
+    for (index, &(line, card, _)) in cards.iter().enumerate() {
+        let after = cards.len() - index - 1;
+        if card.matches() > after {
+            report.warn(
+                line,
+                format!(
+                    "card {} wins {} cards, but there are only {after} after it",
+                    card.card_num,
+                    card.matches()
+                ),
+            );
+        }
+    }
+    report.parse_errors(parsed.errors());
+    report.check_numbering("card", card_nums);
+    report.errors.sort_by_key(|issue| issue.line);
+    report.warnings.sort_by_key(|issue| issue.line);
+    report
+}
+
+mod test {
+
+    #[test]
+    fn example_is_fine() {
+        use crate::validate::validate;
+
+        let input = include_str!("../examples/1.txt");
+        assert!(validate(input).is_empty(), "{}", validate(input));
+    }
+
+    #[test]
+    fn provided_input_is_fine() {
+        use crate::validate::validate;
+
+        let input = aoc::input!();
+        assert!(validate(&input).is_empty(), "{}", validate(&input));
+    }
+
+    #[test]
+    fn reports_odd_cards() {
+        use aoc::input::Input;
+
+        use crate::validate::validate;
+
+        let input = Input::literal(
+            "
+            Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+            Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+            Card 4:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+            Card 4: 41 92 73 84 | 59 84 76 51 58  5 54 83
+            Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36 36
+            Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 0 120
+            Card 7: 31 18 13 56 72 | 74 77 10 23 35 67 36 11 x
+            Card 8: 31 18 13 56 72 | 31 18 13 56 72 74 77 10
+            ",
+        );
+        insta::assert_snapshot!(validate(&input).to_string(), @"
+        error: line 7: column 50: `x` is left over
+        warning: line 3: card 3 is missing
+        warning: line 4: card 4 has 4 winning numbers, card 1 has 5
+        warning: line 4: card 4 is already on line 3
+        warning: line 5: 36 is in the numbers of its own twice
+        warning: line 5: card 5 has 9 numbers of its own, card 1 has 8
+        warning: line 6: 0 isn't from 1 to 99
+        warning: line 6: 120 isn't from 1 to 99
+        warning: line 6: card 6 has 9 numbers of its own, card 1 has 8
+        warning: line 8: card 8 wins 5 cards, but there are only 0 after it
+        warning: line 8: card 7 is missing
+        ");
+    }
+}
@@ -0,0 +1,272 @@
+//! What if a card had matched one more or one fewer number: how part two's total would change, and
+//! which cards' matches it depends on most.
+//!
+//! Re-running the cascade for every card and every what-if would be quadratic, but it doesn't need
+//! re-running. A copy of a card always turns into the same number of cards in the end: itself, and
+//! what each copy it wins turns into. That's its *value*, and since cards only win the cards after
+//! them, a pass from the last card back to the first works every card's value out. Part two's
+//! cascade works out how many copies of each card we end up with, and the total is every card's
+//! copies added up, or every original card's value.
+//!
+//! Changing what a card matches only changes its own value, not the value of the cards after it, so
+//! the total changes by how many copies of it there are, times how much its value changed: the value
+//! of the card it now also wins, or no longer wins.
+
+use aoc::answer::{checked_add, checked_mul, Overflow};
+
+use crate::{part_two::cascade, ScratchoffCard};
+
+/// Part two's cascade of a card, worked out both ways.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct CardCascade {
+    pub card_num: usize,
+    pub matches: usize,
+    /// How many copies of the card we end up with, the original included.
+    pub copies: u128,
+    /// How many cards one copy of this card turns into, itself included.
+    pub value: u128,
+}
+
+/// How much part two's total hangs on a card's matches.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Sensitivity {
+    pub card_num: usize,
+    /// How many more cards we'd end up with if it matched one more number.
+    pub gain: u128,
+    /// How many fewer cards we'd end up with if it matched one fewer, zero if it matched none.
+    pub loss: u128,
+    /// How many fewer cards we'd end up with if it matched nothing at all.
+    pub contribution: u128,
+}
+
+/// The cascade of a pile of cards, for asking what if about.
+///
+/// Like part two, the copies are counted in `u128`s and checked, and so is everything worked out
+/// from them. Each copy of a card turns into cards of its own that no other copy does, so a card's
+/// copies times its value is never more than the total, and taking any of that off the total can't
+/// go below zero.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Cascade {
+    cards: Vec<CardCascade>,
+    total: u128,
+}
+
+impl Cascade {
+    /// Cards only win the cards after them in the pile, so a card can't win past the last one.
+    pub fn new(cards: &[ScratchoffCard]) -> Result<Self, Overflow> {
+        // Part two's cascade already counts the copies.
+        let mut cards = cascade(cards.iter().copied())
+            .map(|card| {
+                let (card, copies) = card?;
+                Ok(CardCascade {
+                    card_num: card.card_num,
+                    matches: card.matches(),
+                    copies,
+                    value: 0,
+                })
+            })
+            .collect::<Result<Vec<_>, Overflow>>()?;
+        let len = cards.len();
+        let last_won = |index: usize, matches: usize| (index + matches).min(len - 1);
+
+        // A card's value is one more than the values of the cards it wins, which are a run of the
+        // cards after it, so the sums of values from each card to the end give it straight away.
+        let mut value_from = vec![0; len + 1];
+        for index in (0..len).rev() {
+            let won = value_from[index + 1] - value_from[last_won(index, cards[index].matches) + 1];
+            cards[index].value = checked_add(1, won)?;
+            value_from[index] = checked_add(value_from[index + 1], cards[index].value)?;
+        }
+
+        // Every card we end up with is one of the original cards or something it turned into.
+        Ok(Self {
+            cards,
+            total: value_from[0],
+        })
+    }
+
+    pub fn cards(&self) -> &[CardCascade] {
+        &self.cards
+    }
+
+    /// How many cards we end up with, which is part two's answer.
+    pub fn total(&self) -> u128 {
+        self.total
+    }
+
+    fn index(&self, card_num: usize) -> Option<usize> {
+        self.cards.iter().position(|card| card.card_num == card_num)
+    }
+
+    /// The value of the card `offset` after the one at `index`, or nothing past the last card.
+    fn value_after(&self, index: usize, offset: usize) -> u128 {
+        self.cards.get(index + offset).map_or(0, |card| card.value)
+    }
+
+    fn sensitivity_at(&self, index: usize) -> Result<Sensitivity, Overflow> {
+        let card = &self.cards[index];
+        let loss = match card.matches {
+            0 => 0,
+            matches => checked_mul(card.copies, self.value_after(index, matches))?,
+        };
+        Ok(Sensitivity {
+            card_num: card.card_num,
+            gain: checked_mul(card.copies, self.value_after(index, card.matches + 1))?,
+            loss,
+            contribution: checked_mul(card.copies, card.value - 1)?,
+        })
+    }
+
+    /// How much the total hangs on a card's matches, `None` if there's no such card.
+    pub fn sensitivity(&self, card_num: usize) -> Option<Result<Sensitivity, Overflow>> {
+        self.index(card_num).map(|index| self.sensitivity_at(index))
+    }
+
+    /// How much the total hangs on each card's matches, in the order of the cards.
+    pub fn sensitivities(&self) -> impl Iterator<Item = Result<Sensitivity, Overflow>> + '_ {
+        (0..self.cards.len()).map(|index| self.sensitivity_at(index))
+    }
+
+    /// The total if a card had matched one more number, `None` if there's no such card.
+    pub fn if_gained(&self, card_num: usize) -> Option<Result<u128, Overflow>> {
+        let sensitivity = self.sensitivity(card_num)?;
+        Some(sensitivity.and_then(|sensitivity| checked_add(self.total, sensitivity.gain)))
+    }
+
+    /// The total if a card had matched one fewer number, `None` if there's no such card or it
+    /// didn't match any.
+    pub fn if_lost(&self, card_num: usize) -> Option<Result<u128, Overflow>> {
+        let index = self.index(card_num)?;
+        (self.cards[index].matches > 0).then(|| {
+            self.sensitivity_at(index)
+                .map(|sensitivity| self.total - sensitivity.loss)
+        })
+    }
+
+    /// The card whose matches the total hangs on most, the first of them if there's a tie.
+    pub fn biggest_contribution(&self) -> Result<Option<Sensitivity>, Overflow> {
+        self.sensitivities()
+            .try_fold(None, |biggest: Option<Sensitivity>, sensitivity| {
+                let sensitivity = sensitivity?;
+                Ok(match biggest {
+                    Some(biggest) if biggest.contribution >= sensitivity.contribution => {
+                        Some(biggest)
+                    }
+                    _ => Some(sensitivity),
+                })
+            })
+    }
+}
+
+mod test {
+
+    #[test]
+    fn example_cascade() {
+        use aoc::parse::Parse;
+
+        use crate::what_if::Cascade;
+        use crate::ScratchoffCard;
+
+        let input = include_str!("../examples/1.txt");
+        let cards = input
+            .lines()
+            .map(|line| ScratchoffCard::parse(line).unwrap().1)
+            .collect::<Vec<_>>();
+        let cascade = Cascade::new(&cards).unwrap();
+        assert_eq!(cascade.total(), 30);
+        assert_eq!(cascade.if_gained(4), Some(Ok(30 + 8)));
+        assert_eq!(cascade.if_lost(1), Some(Ok(30 - 1)));
+        assert_eq!(cascade.if_lost(5), None);
+        assert_eq!(cascade.if_gained(7), None);
+        let biggest = cascade.biggest_contribution().unwrap().unwrap();
+        assert_eq!(biggest.card_num, 1);
+
+        let table = cascade
+            .cards()
+            .iter()
+            .zip(cascade.sensitivities())
+            .map(|(card, sensitivity)| format!("{card:?}\n  {:?}", sensitivity.unwrap()))
+            .collect::<Vec<_>>();
+        insta::assert_snapshot!(table.join("\n"), @"
+        CardCascade { card_num: 1, matches: 4, copies: 1, value: 15 }
+          Sensitivity { card_num: 1, gain: 1, loss: 1, contribution: 14 }
+        CardCascade { card_num: 2, matches: 2, copies: 2, value: 7 }
+          Sensitivity { card_num: 2, gain: 2, loss: 4, contribution: 12 }
+        CardCascade { card_num: 3, matches: 2, copies: 4, value: 4 }
+          Sensitivity { card_num: 3, gain: 4, loss: 4, contribution: 12 }
+        CardCascade { card_num: 4, matches: 1, copies: 8, value: 2 }
+          Sensitivity { card_num: 4, gain: 8, loss: 8, contribution: 8 }
+        CardCascade { card_num: 5, matches: 0, copies: 14, value: 1 }
+          Sensitivity { card_num: 5, gain: 14, loss: 0, contribution: 0 }
+        CardCascade { card_num: 6, matches: 0, copies: 1, value: 1 }
+          Sensitivity { card_num: 6, gain: 0, loss: 0, contribution: 0 }
+        ");
+    }
+
+    /// The same pile as part two's, where every card winning the next ten is well past a `u128`.
+    #[test]
+    fn cascades_that_overflow_are_errors() {
+        use aoc::parse::Parse;
+
+        use crate::what_if::Cascade;
+        use crate::ScratchoffCard;
+
+        let numbers = (1..=10)
+            .map(|num| num.to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let cards = (1..=200)
+            .map(|card_num| format!("Card {card_num}: {numbers} | {numbers}"))
+            .map(|line| ScratchoffCard::parse(&line).unwrap().1)
+            .collect::<Vec<_>>();
+        assert!(Cascade::new(&cards).is_err());
+        let cascade = Cascade::new(&cards[..50]).unwrap();
+        assert!(cascade
+            .sensitivities()
+            .all(|sensitivity| sensitivity.is_ok()));
+    }
+
+    #[test]
+    fn agrees_with_rerunning_the_cascade() {
+        use aoc::parse::Parse;
+
+        use crate::what_if::Cascade;
+        use crate::ScratchoffCard;
+
+        /// The total the slow way, re-running the cascade with every card's matches.
+        fn rerun(matches: &[usize]) -> u128 {
+            let mut copies = vec![1; matches.len()];
+            for index in 0..matches.len() {
+                for won in index + 1..=(index + matches[index]).min(matches.len() - 1) {
+                    copies[won] += copies[index];
+                }
+            }
+            copies.iter().sum()
+        }
+
+        let input = aoc::input!();
+        let cards = input
+            .lines()
+            .map(|line| ScratchoffCard::parse(line).unwrap().1)
+            .collect::<Vec<_>>();
+        let cascade = Cascade::new(&cards).unwrap();
+        assert_eq!(cascade.total(), 14427616);
+
+        let matches = cards.iter().map(|card| card.matches()).collect::<Vec<_>>();
+        for (index, card) in cards.iter().enumerate() {
+            let what_if = |matched: usize| {
+                let mut what_if = matches.clone();
+                what_if[index] = matched;
+                rerun(&what_if)
+            };
+            let matched = matches[index];
+            let gained = Some(Ok(what_if(matched + 1)));
+            assert_eq!(cascade.if_gained(card.card_num), gained);
+            let lost = matched.checked_sub(1).map(|matched| Ok(what_if(matched)));
+            assert_eq!(cascade.if_lost(card.card_num), lost);
+            let sensitivity = cascade.sensitivity(card.card_num).unwrap();
+            let contribution = sensitivity.unwrap().contribution;
+            assert_eq!(cascade.total() - contribution, what_if(0));
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use std::{collections::HashMap, hash::Hash};
+
+use crate::Graph;
+
+/// A graph with its edges written down, for when the puzzle input lists them.
+///
+/// Nodes keep the order they were added in, so searches and orderings come out the same every run.
+#[derive(Debug, Clone)]
+pub struct AdjacencyList<N> {
+    index: HashMap<N, usize>,
+    nodes: Vec<N>,
+    /// For each node, the index of the node each of its edges goes to, and the edge's cost.
+    edges: Vec<Vec<(usize, usize)>>,
+}
+
+impl<N> Default for AdjacencyList<N> {
+    fn default() -> Self {
+        Self {
+            index: HashMap::new(),
+            nodes: vec![],
+            edges: vec![],
+        }
+    }
+}
+
+impl<N: Clone + Eq + Hash> AdjacencyList<N> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the node if it's new, either way returning its index.
+    pub fn add_node(&mut self, node: N) -> usize {
+        if let Some(&index) = self.index.get(&node) {
+            return index;
+        }
+        let index = self.nodes.len();
+        self.index.insert(node.clone(), index);
+        self.nodes.push(node);
+        self.edges.push(vec![]);
+        index
+    }
+
+    /// Adds an edge from `from` to `to`, and the nodes if they're new.
+    pub fn add_edge(&mut self, from: N, to: N, cost: usize) {
+        let from = self.add_node(from);
+        let to = self.add_node(to);
+        self.edges[from].push((to, cost));
+    }
+
+    /// Every node, in the order they were added.
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.nodes.iter()
+    }
+
+    /// Every edge as `(from, to, cost)`, in the order they were added from each node.
+    pub fn all_edges(&self) -> impl Iterator<Item = (&N, &N, usize)> {
+        self.edges
+            .iter()
+            .enumerate()
+            .flat_map(move |(from, edges)| {
+                edges
+                    .iter()
+                    .map(move |&(to, cost)| (&self.nodes[from], &self.nodes[to], cost))
+            })
+    }
+}
+
+impl<N: Clone + Eq + Hash> FromIterator<(N, N, usize)> for AdjacencyList<N> {
+    /// Builds the graph from `(from, to, cost)` edges.
+    fn from_iter<I: IntoIterator<Item = (N, N, usize)>>(edges: I) -> Self {
+        let mut graph = Self::new();
+        for (from, to, cost) in edges {
+            graph.add_edge(from, to, cost);
+        }
+        graph
+    }
+}
+
+impl<N: Clone + Eq + Hash> Graph for AdjacencyList<N> {
+    type Node = N;
+
+    fn edges(&self, node: &N) -> impl Iterator<Item = (N, usize)> {
+        self.index.get(node).into_iter().flat_map(move |&from| {
+            self.edges[from]
+                .iter()
+                .map(move |&(to, cost)| (self.nodes[to].clone(), cost))
+        })
+    }
+}
@@ -0,0 +1,88 @@
+use grid::{Direction, Grid, Point};
+
+use crate::Graph;
+
+/// A grid as a graph, each cell having edges to its neighbours.
+pub struct GridGraph<'a, T, F> {
+    grid: &'a Grid<T>,
+    cost: F,
+    diagonals: bool,
+}
+
+impl<'a, T, F> GridGraph<'a, T, F>
+where
+    F: Fn(&T, &T) -> Option<usize>,
+{
+    /// `cost` says what stepping from one cell to its neighbour costs, or `None` if we can't.
+    ///
+    /// ```
+    /// use graph::{bfs, GridGraph};
+    /// use grid::{Grid, Point};
+    /// let maze = Grid::parse("..#\n#..", |c| c).unwrap();
+    /// let graph = GridGraph::new(&maze, |_, to| (*to != '#').then_some(1));
+    /// let path = bfs(&graph, Point::new(0, 0), |point| *point == Point::new(2, 1)).unwrap();
+    /// assert_eq!(path.cost, 3);
+    /// ```
+    pub fn new(grid: &'a Grid<T>, cost: F) -> Self {
+        Self {
+            grid,
+            cost,
+            diagonals: false,
+        }
+    }
+
+    /// Lets us step diagonally too.
+    pub fn with_diagonals(self) -> Self {
+        Self {
+            diagonals: true,
+            ..self
+        }
+    }
+}
+
+impl<T, F> Graph for GridGraph<'_, T, F>
+where
+    F: Fn(&T, &T) -> Option<usize>,
+{
+    type Node = Point;
+
+    fn edges(&self, point: &Point) -> impl Iterator<Item = (Point, usize)> {
+        let directions: &'static [Direction] = if self.diagonals {
+            &Direction::ALL
+        } else {
+            &Direction::CARDINAL
+        };
+        let from = self.grid.get(*point);
+        directions.iter().filter_map(move |direction| {
+            let next = point.step(*direction);
+            let cost = (self.cost)(from?, self.grid.get(next)?)?;
+            Some((next, cost))
+        })
+    }
+}
+
+mod test {
+
+    #[test]
+    fn diagonals_cut_corners() {
+        use crate::{bfs, GridGraph};
+        use grid::{Grid, Point};
+
+        let open = Grid::new(5, 5, ());
+        let corner = Point::new(4, 4);
+        let straight = GridGraph::new(&open, |_, _| Some(1));
+        assert_eq!(
+            bfs(&straight, Point::ORIGIN, |point| *point == corner)
+                .unwrap()
+                .cost,
+            8
+        );
+        let diagonal = GridGraph::new(&open, |_, _| Some(1)).with_diagonals();
+        assert_eq!(
+            bfs(&diagonal, Point::ORIGIN, |point| *point == corner)
+                .unwrap()
+                .cost,
+            4
+        );
+    }
+}
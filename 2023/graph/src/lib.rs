@@ -0,0 +1,21 @@
+use std::hash::Hash;
+
+mod adjacency;
+mod grid;
+mod order;
+mod search;
+
+pub use adjacency::AdjacencyList;
+pub use grid::GridGraph;
+pub use order::{find_cycle, topological_sort};
+pub use search::{astar, bfs, bfs_distances, dijkstra, dijkstra_distances, Path};
+
+/// Something we can search, by asking each node where its edges go.
+///
+/// The nodes don't have to be known up front, so puzzles can build them as the search goes.
+pub trait Graph {
+    type Node: Clone + Eq + Hash;
+
+    /// The nodes one edge away from `node`, with what getting there costs.
+    fn edges(&self, node: &Self::Node) -> impl Iterator<Item = (Self::Node, usize)>;
+}
@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+
+use crate::Graph;
+
+#[derive(PartialEq, Eq, Clone, Copy)]
+enum State {
+    /// On the current path of the depth first search.
+    Open,
+    /// Everything reachable from the node has been ordered.
+    Done,
+}
+
+/// Orders every node reachable from `roots` so each edge goes from an earlier node to a later one.
+///
+/// If there's no such order, returns a cycle instead, each node having an edge to the next and
+/// the last one an edge back to the first.
+///
+/// ```
+/// use graph::{topological_sort, AdjacencyList};
+/// let graph = AdjacencyList::from_iter([("socks", "shoes", 0), ("pants", "shoes", 0)]);
+/// let order = topological_sort(&graph, graph.nodes().copied()).unwrap();
+/// assert_eq!(order.last(), Some(&"shoes"));
+/// ```
+pub fn topological_sort<G: Graph>(
+    graph: &G,
+    roots: impl IntoIterator<Item = G::Node>,
+) -> Result<Vec<G::Node>, Vec<G::Node>> {
+    let mut states = HashMap::new();
+    let mut order = vec![];
+    for root in roots {
+        if states.contains_key(&root) {
+            continue;
+        }
+        states.insert(root.clone(), State::Open);
+        // Each node on the path, and the edges of it still to follow. Kept on the heap rather than
+        // recursing, so long chains can't blow the stack.
+        let mut path = vec![(root.clone(), next_nodes(graph, &root))];
+        while let Some((_, edges)) = path.last_mut() {
+            match edges.pop() {
+                Some(next) => match states.get(&next) {
+                    None => {
+                        states.insert(next.clone(), State::Open);
+                        let edges = next_nodes(graph, &next);
+                        path.push((next, edges));
+                    }
+                    Some(State::Open) => {
+                        let start = path
+                            .iter()
+                            .position(|(node, _)| *node == next)
+                            .expect("open nodes are on the path");
+                        return Err(path.drain(start..).map(|(node, _)| node).collect());
+                    }
+                    Some(State::Done) => {}
+                },
+                None => {
+                    let (node, _) = path.pop().expect("we just looked at it");
+                    states.insert(node.clone(), State::Done);
+                    order.push(node);
+                }
+            }
+        }
+    }
+    order.reverse();
+    Ok(order)
+}
+
+/// The nodes `node` has edges to, backwards so popping them follows the edges in order.
+fn next_nodes<G: Graph>(graph: &G, node: &G::Node) -> Vec<G::Node> {
+    let mut nodes = graph.edges(node).map(|(next, _)| next).collect::<Vec<_>>();
+    nodes.reverse();
+    nodes
+}
+
+/// A cycle reachable from `roots`, if there is one.
+pub fn find_cycle<G: Graph>(
+    graph: &G,
+    roots: impl IntoIterator<Item = G::Node>,
+) -> Option<Vec<G::Node>> {
+    topological_sort(graph, roots).err()
+}
+
+mod test {
+
+    #[test]
+    fn orders_edges_forwards() {
+        use crate::{find_cycle, topological_sort, AdjacencyList};
+
+        let graph = AdjacencyList::from_iter([
+            (5, 11, 0),
+            (7, 11, 0),
+            (7, 8, 0),
+            (3, 8, 0),
+            (3, 10, 0),
+            (11, 2, 0),
+            (11, 9, 0),
+            (11, 10, 0),
+            (8, 9, 0),
+        ]);
+        let order = topological_sort(&graph, graph.nodes().copied()).unwrap();
+        assert_eq!(order.len(), 8);
+        let position = |node| order.iter().position(|n| *n == node).unwrap();
+        for (from, to, _) in graph.all_edges() {
+            assert!(position(*from) < position(*to), "{from} -> {to}");
+        }
+        assert_eq!(find_cycle(&graph, graph.nodes().copied()), None);
+    }
+
+    #[test]
+    fn finds_cycles() {
+        use crate::{find_cycle, topological_sort, AdjacencyList};
+
+        let graph =
+            AdjacencyList::from_iter([("a", "b", 0), ("b", "c", 0), ("c", "d", 0), ("d", "b", 0)]);
+        assert_eq!(find_cycle(&graph, ["a"]), Some(vec!["b", "c", "d"]));
+        assert_eq!(topological_sort(&graph, ["a"]), Err(vec!["b", "c", "d"]));
+
+        let graph = AdjacencyList::from_iter([("a", "a", 0)]);
+        assert_eq!(find_cycle(&graph, ["a"]), Some(vec!["a"]));
+    }
+
+    #[test]
+    fn long_chains_dont_overflow() {
+        use crate::{topological_sort, AdjacencyList};
+
+        let graph = AdjacencyList::from_iter((0..100_000).map(|node| (node, node + 1, 0)));
+        let order = topological_sort(&graph, [0]).unwrap();
+        assert_eq!(order.len(), 100_001);
+        assert!(order.windows(2).all(|pair| pair[0] + 1 == pair[1]));
+    }
+}
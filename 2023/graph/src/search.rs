@@ -0,0 +1,248 @@
+use std::{
+    cmp::Reverse,
+    collections::{BinaryHeap, HashMap, VecDeque},
+};
+
+use crate::Graph;
+
+/// How to get from the start of a search to where it ended up.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Path<N> {
+    /// From the start to the goal, both included.
+    pub nodes: Vec<N>,
+    pub cost: usize,
+}
+
+/// The nodes a search has reached, how, and what it cost.
+///
+/// Nodes get an index when they're first reached, so the queues don't need to order or clone them.
+struct Visited<N> {
+    index: HashMap<N, usize>,
+    nodes: Vec<N>,
+    parents: Vec<Option<usize>>,
+    costs: Vec<usize>,
+}
+
+impl<N: Clone + Eq + std::hash::Hash> Visited<N> {
+    fn new(start: N) -> Self {
+        Self {
+            index: HashMap::from([(start.clone(), 0)]),
+            nodes: vec![start],
+            parents: vec![None],
+            costs: vec![0],
+        }
+    }
+
+    /// Records that we can reach `node` from `parent` for `cost`, if that's the cheapest way
+    /// there yet, returning the node's index if so.
+    fn reach(&mut self, node: N, parent: usize, cost: usize) -> Option<usize> {
+        match self.index.get(&node) {
+            Some(&index) if self.costs[index] <= cost => None,
+            Some(&index) => {
+                self.parents[index] = Some(parent);
+                self.costs[index] = cost;
+                Some(index)
+            }
+            None => {
+                let index = self.nodes.len();
+                self.index.insert(node.clone(), index);
+                self.nodes.push(node);
+                self.parents.push(Some(parent));
+                self.costs.push(cost);
+                Some(index)
+            }
+        }
+    }
+
+    fn path(&self, mut index: usize) -> Path<N> {
+        let cost = self.costs[index];
+        let mut nodes = vec![self.nodes[index].clone()];
+        while let Some(parent) = self.parents[index] {
+            nodes.push(self.nodes[parent].clone());
+            index = parent;
+        }
+        nodes.reverse();
+        Path { nodes, cost }
+    }
+
+    fn into_costs(self) -> HashMap<N, usize> {
+        self.nodes.into_iter().zip(self.costs).collect()
+    }
+}
+
+fn breadth_first<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    mut is_goal: impl FnMut(&G::Node) -> bool,
+) -> (Visited<G::Node>, Option<usize>) {
+    let mut visited = Visited::new(start);
+    let mut queue = VecDeque::from([0]);
+    while let Some(index) = queue.pop_front() {
+        let node = visited.nodes[index].clone();
+        if is_goal(&node) {
+            return (visited, Some(index));
+        }
+        let steps = visited.costs[index] + 1;
+        for (next, _) in graph.edges(&node) {
+            // The first time we reach a node is the fewest steps it takes, so never re-reach one.
+            if !visited.index.contains_key(&next) {
+                queue.extend(visited.reach(next, index, steps));
+            }
+        }
+    }
+    (visited, None)
+}
+
+/// The path to the first node `is_goal` accepts that takes the fewest edges, ignoring their costs.
+///
+/// The path's cost is how many edges it takes.
+pub fn bfs<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    is_goal: impl FnMut(&G::Node) -> bool,
+) -> Option<Path<G::Node>> {
+    let (visited, goal) = breadth_first(graph, start, is_goal);
+    goal.map(|goal| visited.path(goal))
+}
+
+/// How many edges it takes to get to every node we can reach from `start`.
+pub fn bfs_distances<G: Graph>(graph: &G, start: G::Node) -> HashMap<G::Node, usize> {
+    breadth_first(graph, start, |_| false).0.into_costs()
+}
+
+fn best_first<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    mut is_goal: impl FnMut(&G::Node) -> bool,
+    heuristic: impl Fn(&G::Node) -> usize,
+) -> (Visited<G::Node>, Option<usize>) {
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), 0, 0))]);
+    let mut visited = Visited::new(start);
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        // We found a cheaper way here after queueing this one.
+        if cost > visited.costs[index] {
+            continue;
+        }
+        let node = visited.nodes[index].clone();
+        if is_goal(&node) {
+            return (visited, Some(index));
+        }
+        for (next, weight) in graph.edges(&node) {
+            let next_cost = cost + weight;
+            let estimate = next_cost + heuristic(&next);
+            if let Some(next) = visited.reach(next, index, next_cost) {
+                queue.push(Reverse((estimate, next_cost, next)));
+            }
+        }
+    }
+    (visited, None)
+}
+
+/// The cheapest path to the first node `is_goal` accepts.
+pub fn dijkstra<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    is_goal: impl FnMut(&G::Node) -> bool,
+) -> Option<Path<G::Node>> {
+    astar(graph, start, is_goal, |_| 0)
+}
+
+/// What the cheapest path to every node we can reach from `start` costs.
+pub fn dijkstra_distances<G: Graph>(graph: &G, start: G::Node) -> HashMap<G::Node, usize> {
+    best_first(graph, start, |_| false, |_| 0).0.into_costs()
+}
+
+/// Like [`dijkstra`], heading for the nodes `heuristic` says are closest to a goal first.
+///
+/// The path is only the cheapest if `heuristic` never overestimates the cost left to a goal.
+pub fn astar<G: Graph>(
+    graph: &G,
+    start: G::Node,
+    is_goal: impl FnMut(&G::Node) -> bool,
+    heuristic: impl Fn(&G::Node) -> usize,
+) -> Option<Path<G::Node>> {
+    let (visited, goal) = best_first(graph, start, is_goal, heuristic);
+    goal.map(|goal| visited.path(goal))
+}
+
+mod test {
+
+    #[allow(dead_code)]
+    const MAZE: &str = "
+        S.#.....
+        .##.###.
+        ....#...
+        .##...#E
+    ";
+
+    #[test]
+    fn searches_maze() {
+        use aoc::input::Input;
+        use grid::{Grid, Point};
+
+        use crate::{astar, bfs, bfs_distances, dijkstra, GridGraph};
+
+        let maze = Grid::parse(&Input::literal(MAZE), |c| c).unwrap();
+        let graph = GridGraph::new(&maze, |_, to| (*to != '#').then_some(1));
+        let start = Point::new(0, 0);
+        let end = Point::new(7, 3);
+
+        let path = bfs(&graph, start, |point| *point == end).unwrap();
+        assert_eq!(path.cost, 12);
+        assert_eq!(path.nodes.len(), 13);
+        assert_eq!((path.nodes[0], path.nodes[12]), (start, end));
+        assert!(path
+            .nodes
+            .windows(2)
+            .all(|step| step[0].manhattan(step[1]) == 1 && maze[step[1]] != '#'));
+
+        assert_eq!(
+            dijkstra(&graph, start, |point| *point == end).unwrap().cost,
+            12
+        );
+        assert_eq!(
+            astar(
+                &graph,
+                start,
+                |point| *point == end,
+                |point| point.manhattan(end)
+            )
+            .unwrap()
+            .cost,
+            12
+        );
+
+        let distances = bfs_distances(&graph, start);
+        assert_eq!(distances[&end], 12);
+        assert_eq!(
+            distances.len(),
+            maze.iter().filter(|(_, c)| **c != '#').count()
+        );
+        assert_eq!(bfs(&graph, start, |point| maze[*point] == '#'), None);
+    }
+
+    #[test]
+    fn takes_the_cheap_way_round() {
+        use crate::{bfs, dijkstra, dijkstra_distances, AdjacencyList};
+
+        let graph = AdjacencyList::from_iter([
+            ("a", "b", 10),
+            ("a", "c", 1),
+            ("c", "d", 1),
+            ("d", "b", 1),
+            ("b", "e", 1),
+        ]);
+
+        let path = dijkstra(&graph, "a", |node| *node == "e").unwrap();
+        assert_eq!(path.nodes, ["a", "c", "d", "b", "e"]);
+        assert_eq!(path.cost, 4);
+
+        let path = bfs(&graph, "a", |node| *node == "e").unwrap();
+        assert_eq!(path.nodes, ["a", "b", "e"]);
+        assert_eq!(path.cost, 2);
+
+        let distances = dijkstra_distances(&graph, "c");
+        assert_eq!(distances.get("a"), None);
+        assert_eq!(distances["e"], 3);
+    }
+}
@@ -0,0 +1,446 @@
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+mod point;
+
+pub use point::{Direction, Point};
+
+/// A rectangle of cells, stored row by row.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+/// Why some text didn't make a grid.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ParseError {
+    /// A row wasn't as wide as the first one.
+    Ragged {
+        row: usize,
+        width: usize,
+        expected: usize,
+    },
+    /// The mapping didn't know what to make of a character.
+    UnexpectedChar { point: Point, c: char },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Ragged {
+                row,
+                width,
+                expected,
+            } => write!(f, "row {row} is {width} wide, expected {expected}"),
+            ParseError::UnexpectedChar { point, c } => {
+                write!(f, "unexpected {c:?} at {}, {}", point.x, point.y)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl<T> Grid<T> {
+    /// A grid with no columns has no rows either, whatever `height` says.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        let height = if width == 0 { 0 } else { height };
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid out of rows, which all have to be as wide as each other.
+    ///
+    /// Rows with nothing in them make an empty grid, as there'd be no cells to have rows of.
+    pub fn from_rows(rows: impl IntoIterator<Item = Vec<T>>) -> Result<Self, ParseError> {
+        let mut grid = Self {
+            width: 0,
+            height: 0,
+            cells: vec![],
+        };
+        for row in rows {
+            if grid.height == 0 {
+                grid.width = row.len();
+            } else if row.len() != grid.width {
+                return Err(ParseError::Ragged {
+                    row: grid.height,
+                    width: row.len(),
+                    expected: grid.width,
+                });
+            }
+            grid.cells.extend(row);
+            grid.height += 1;
+        }
+        if grid.width == 0 {
+            grid.height = 0;
+        }
+        Ok(grid)
+    }
+
+    /// Reads a grid a character per cell, a line per row, giving up on characters `cell` returns
+    /// `None` for.
+    ///
+    /// Lines are taken as they are, since spaces and blank rows can be part of a grid, so indented
+    /// test literals want dedenting with `aoc::input::Input::literal` first.
+    ///
+    /// ```
+    /// use grid::{Grid, Point};
+    /// let grid = Grid::try_parse("#.\n.#", |c| match c {
+    ///     '#' => Some(true),
+    ///     '.' => Some(false),
+    ///     _ => None,
+    /// })
+    /// .unwrap();
+    /// assert!(grid[Point::new(1, 1)]);
+    /// assert!(Grid::try_parse("#?", |c| (c == '#').then_some(true)).is_err());
+    /// ```
+    pub fn try_parse(
+        input: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let rows = input
+            .lines()
+            .enumerate()
+            .map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(x, c)| {
+                        cell(c).ok_or(ParseError::UnexpectedChar {
+                            point: Point::new(x as isize, y as isize),
+                            c,
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Self::from_rows(rows)
+    }
+
+    /// Like [`Grid::try_parse`], for mappings that take any character.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> T) -> Result<Self, ParseError> {
+        Self::try_parse(input, |c| Some(cell(c)))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        (0..self.width as isize).contains(&point.x) && (0..self.height as isize).contains(&point.y)
+    }
+
+    fn offset(&self, point: Point) -> Option<usize> {
+        self.contains(point)
+            .then(|| point.y as usize * self.width + point.x as usize)
+    }
+
+    /// The cell at the point, or `None` if it's off the grid.
+    pub fn get(&self, point: Point) -> Option<&T> {
+        self.offset(point).map(|offset| &self.cells[offset])
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.offset(point).map(|offset| &mut self.cells[offset])
+    }
+
+    /// Every point on the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height as isize)
+            .flat_map(move |y| (0..width as isize).map(move |x| Point::new(x, y)))
+    }
+
+    /// Every cell along with where it is, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    /// The neighbours of the point sharing an edge with it, leaving out those off the grid.
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        point
+            .neighbours4()
+            .filter_map(|neighbour| Some((neighbour, self.get(neighbour)?)))
+    }
+
+    /// The neighbours of the point sharing an edge or a corner with it, leaving out those off the
+    /// grid.
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = (Point, &T)> {
+        point
+            .neighbours8()
+            .filter_map(|neighbour| Some((neighbour, self.get(neighbour)?)))
+    }
+
+    /// The cells from `start`, stepping in the direction until we fall off the grid.
+    ///
+    /// ```
+    /// use grid::{Direction, Grid, Point};
+    /// let grid = Grid::parse("abc\ndef\nghi", |c| c).unwrap();
+    /// let diagonal = grid.walk(Point::ORIGIN, Direction::DownRight);
+    /// assert_eq!(diagonal.map(|(_, c)| c).collect::<String>(), "aei");
+    /// ```
+    pub fn walk(&self, start: Point, direction: Direction) -> impl Iterator<Item = (Point, &T)> {
+        std::iter::successors(Some(start), move |point| Some(point.step(direction)))
+            .map_while(|point| Some((point, self.get(point)?)))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on 0, and an empty grid has no rows anyway.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// # Panics
+    ///
+    /// If `x` is off the grid, like [`Grid::row`] does for `y`, rather than wandering into the
+    /// rows below.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {x} is off a grid {} wide",
+            self.width
+        );
+        self.cells
+            .iter()
+            .skip(x)
+            .step_by(self.width.max(1))
+            .take(self.height)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Mirrors the grid along the diagonal from the top left, so rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_rows((0..self.width).map(|x| self.column(x).cloned().collect())).unwrap()
+    }
+
+    /// A quarter turn clockwise, so the first column becomes the first row, backwards.
+    ///
+    /// ```
+    /// use grid::Grid;
+    /// let grid = Grid::parse("ab\ncd\nef", |c| c).unwrap();
+    /// assert_eq!(grid.rotate_right().to_string(), "eca\nfdb\n");
+    /// ```
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_rows((0..self.width).map(|x| {
+            let mut row = self.column(x).cloned().collect::<Vec<_>>();
+            row.reverse();
+            row
+        }))
+        .unwrap()
+    }
+
+    /// A quarter turn counterclockwise, so the last column becomes the first row.
+    pub fn rotate_left(&self) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_rows(
+            (0..self.width)
+                .rev()
+                .map(|x| self.column(x).cloned().collect()),
+        )
+        .unwrap()
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{point:?} is off the {}x{} grid", self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{point:?} is off the {width}x{height} grid"))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    /// Each cell as it displays, a line per row.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+mod test {
+
+    #[test]
+    fn parses_and_indexes() {
+        use aoc::input::Input;
+
+        use crate::{Grid, ParseError, Point};
+
+        let input = Input::literal(
+            "
+            123
+            456
+            ",
+        );
+        let mut grid = Grid::parse(&input, |c| c.to_digit(10).unwrap()).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Point::new(2, 1)], 6);
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.get(Point::new(0, -1)), None);
+
+        grid[Point::new(0, 0)] = 9;
+        assert_eq!(grid.row(0), [9, 2, 3]);
+        assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), [2, 5]);
+
+        assert_eq!(
+            Grid::parse("12\n3", |c| c),
+            Err(ParseError::Ragged {
+                row: 1,
+                width: 1,
+                expected: 2
+            })
+        );
+    }
+
+    #[test]
+    fn spaces_are_cells() {
+        use crate::{Grid, ParseError, Point};
+
+        let grid = Grid::parse(" #\n# ", |c| c).unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert_eq!(grid[Point::new(0, 0)], ' ');
+        assert_eq!(grid[Point::new(0, 1)], '#');
+
+        assert_eq!(
+            Grid::parse("#.\n\n.#", |c| c),
+            Err(ParseError::Ragged {
+                row: 1,
+                width: 0,
+                expected: 2
+            })
+        );
+    }
+
+    #[test]
+    fn neighbours_stay_on_the_grid() {
+        use crate::{Grid, Point};
+
+        let grid = Grid::parse("abc\ndef\nghi", |c| c).unwrap();
+        let corner = grid
+            .neighbours8(Point::ORIGIN)
+            .map(|(_, c)| *c)
+            .collect::<String>();
+        assert_eq!(corner, "bed");
+        let middle = grid
+            .neighbours4(Point::new(1, 1))
+            .map(|(_, c)| *c)
+            .collect::<String>();
+        assert_eq!(middle, "bfhd");
+        assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn transforms() {
+        use crate::{Direction, Grid, Point};
+
+        let grid = Grid::parse("abcd\nefgh\nijkl", |c| c).unwrap();
+        insta::assert_snapshot!(grid.transpose(), @"
+        aei
+        bfj
+        cgk
+        dhl
+        ");
+        insta::assert_snapshot!(grid.rotate_right(), @"
+        iea
+        jfb
+        kgc
+        lhd
+        ");
+        insta::assert_snapshot!(grid.rotate_left(), @"
+        dhl
+        cgk
+        bfj
+        aei
+        ");
+
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+        assert_eq!(grid.transpose().transpose(), grid);
+        assert_eq!(
+            grid.rotate_right().rotate_right(),
+            grid.rotate_left().rotate_left()
+        );
+
+        let antidiagonal = grid
+            .walk(Point::new(3, 0), Direction::DownLeft)
+            .map(|(_, c)| *c)
+            .collect::<String>();
+        assert_eq!(antidiagonal, "dgj");
+        assert_eq!(
+            grid.map(|c| c.to_ascii_uppercase()).row(1),
+            ['E', 'F', 'G', 'H']
+        );
+    }
+
+    #[test]
+    fn empty_grid() {
+        use crate::Grid;
+
+        let grid = Grid::<char>::parse("", |c| c).unwrap();
+        assert_eq!((grid.width(), grid.height()), (0, 0));
+        assert_eq!(grid.points().count(), 0);
+        assert_eq!(grid.rows().count(), 0);
+        assert_eq!(grid.to_string(), "");
+
+        for grid in [
+            Grid::<char>::from_rows([vec![], vec![]]).unwrap(),
+            Grid::new(0, 3, '.'),
+        ] {
+            assert_eq!((grid.width(), grid.height()), (0, 0));
+            assert_eq!(grid.rows().count(), 0);
+            assert_eq!(grid.transpose(), grid);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "column 2 is off a grid 2 wide")]
+    fn columns_stay_on_the_grid() {
+        use crate::Grid;
+
+        let grid = Grid::parse("ab\ncd", |c| c).unwrap();
+        let _ = grid.column(2).count();
+    }
+}
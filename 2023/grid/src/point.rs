@@ -0,0 +1,211 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub};
+
+/// A cell of a grid, `x` counting columns to the right and `y` rows down from the top left.
+///
+/// Signed, so stepping off the top or left edge gives a point the grid just doesn't contain.
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    /// The point one step away in the direction.
+    pub fn step(self, direction: Direction) -> Self {
+        self + direction.offset()
+    }
+
+    /// The four points sharing an edge with this one, in [`Direction::CARDINAL`] order.
+    pub fn neighbours4(self) -> impl Iterator<Item = Point> {
+        Direction::CARDINAL
+            .into_iter()
+            .map(move |direction| self.step(direction))
+    }
+
+    /// The eight points sharing an edge or a corner with this one, in [`Direction::ALL`] order.
+    pub fn neighbours8(self) -> impl Iterator<Item = Point> {
+        Direction::ALL
+            .into_iter()
+            .map(move |direction| self.step(direction))
+    }
+
+    /// How many steps it takes to get to `other` without going diagonally.
+    ///
+    /// ```
+    /// use grid::Point;
+    /// assert_eq!(Point::new(1, 2).manhattan(Point::new(-2, 6)), 7);
+    /// ```
+    pub fn manhattan(self, other: Point) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Point;
+
+    fn mul(self, scale: isize) -> Point {
+        Point::new(self.x * scale, self.y * scale)
+    }
+}
+
+/// Which way to step on a grid, up being towards the first row.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// Clockwise from up.
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub const DIAGONAL: [Direction; 4] = [
+        Direction::UpRight,
+        Direction::DownRight,
+        Direction::DownLeft,
+        Direction::UpLeft,
+    ];
+
+    /// Clockwise from up.
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    pub fn offset(self) -> Point {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::UpRight => Point::new(1, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::DownRight => Point::new(1, 1),
+            Direction::Down => Point::new(0, 1),
+            Direction::DownLeft => Point::new(-1, 1),
+            Direction::Left => Point::new(-1, 0),
+            Direction::UpLeft => Point::new(-1, -1),
+        }
+    }
+
+    /// An eighth of a turn clockwise.
+    fn rotate(self, eighths: usize) -> Self {
+        Direction::ALL[(self as usize + eighths) % 8]
+    }
+
+    /// A quarter turn clockwise.
+    ///
+    /// ```
+    /// use grid::Direction;
+    /// assert_eq!(Direction::Left.turn_right(), Direction::Up);
+    /// assert_eq!(Direction::UpRight.turn_right(), Direction::DownRight);
+    /// ```
+    pub fn turn_right(self) -> Self {
+        self.rotate(2)
+    }
+
+    /// A quarter turn counterclockwise.
+    pub fn turn_left(self) -> Self {
+        self.rotate(6)
+    }
+
+    pub fn opposite(self) -> Self {
+        self.rotate(4)
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+}
+
+mod test {
+
+    #[test]
+    fn turns_around() {
+        use crate::Direction;
+
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_right().turn_right(), direction.opposite());
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.opposite().offset(), -direction.offset());
+        }
+        assert!(Direction::CARDINAL
+            .iter()
+            .all(|direction| !direction.is_diagonal()));
+        assert!(Direction::DIAGONAL
+            .iter()
+            .all(|direction| direction.is_diagonal()));
+    }
+
+    #[test]
+    fn neighbours() {
+        use crate::Point;
+
+        let point = Point::new(3, 5);
+        assert_eq!(
+            point.neighbours4().collect::<Vec<_>>(),
+            [
+                Point::new(3, 4),
+                Point::new(4, 5),
+                Point::new(3, 6),
+                Point::new(2, 5)
+            ]
+        );
+        assert_eq!(point.neighbours8().count(), 8);
+        assert!(point
+            .neighbours8()
+            .all(|neighbour| neighbour.x.abs_diff(3) <= 1
+                && neighbour.y.abs_diff(5) <= 1
+                && neighbour != point));
+    }
+}
@@ -0,0 +1,101 @@
+/// How many ways there are to pick `k` things out of `n`, or `None` if it doesn't fit.
+///
+/// ```
+/// assert_eq!(math::binomial(5, 2), Some(10));
+/// assert_eq!(math::binomial(2, 5), Some(0));
+/// ```
+pub fn binomial(n: u64, k: u64) -> Option<u64> {
+    if k > n {
+        return Some(0);
+    }
+    let k = k.min(n - k);
+    let mut count: u128 = 1;
+    for i in 0..k {
+        // The product of i + 1 consecutive numbers is divisible by (i + 1)!, so this stays exact.
+        count = count.checked_mul(u128::from(n - i))? / u128::from(i + 1);
+    }
+    count.try_into().ok()
+}
+
+/// Every way to pick `k` of the items, keeping their order, first to last.
+///
+/// ```
+/// let pairs = math::combinations(&[1, 2, 3], 2).collect::<Vec<_>>();
+/// assert_eq!(pairs, [[&1, &2], [&1, &3], [&2, &3]]);
+/// ```
+pub fn combinations<T>(items: &[T], k: usize) -> Combinations<'_, T> {
+    Combinations {
+        items,
+        indices: (0..k).collect(),
+        done: k > items.len(),
+    }
+}
+
+pub struct Combinations<'a, T> {
+    items: &'a [T],
+    /// Which items the next combination picks, always increasing.
+    indices: Vec<usize>,
+    done: bool,
+}
+
+impl<'a, T> Iterator for Combinations<'a, T> {
+    type Item = Vec<&'a T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let combination = self.indices.iter().map(|&i| &self.items[i]).collect();
+
+        // Bump the rightmost index that still has room, and pack the ones after it up behind it.
+        let (n, k) = (self.items.len(), self.indices.len());
+        match (0..k).rev().find(|&i| self.indices[i] != i + n - k) {
+            Some(i) => {
+                self.indices[i] += 1;
+                for j in i + 1..k {
+                    self.indices[j] = self.indices[j - 1] + 1;
+                }
+            }
+            None => self.done = true,
+        }
+        Some(combination)
+    }
+}
+
+mod test {
+
+    #[test]
+    fn counts() {
+        use crate::binomial;
+
+        assert_eq!(binomial(0, 0), Some(1));
+        assert_eq!(binomial(52, 5), Some(2598960));
+        assert_eq!(binomial(67, 33), Some(14226520737620288370));
+        assert_eq!(binomial(68, 34), None);
+        // Fits, even though n! is way past u128.
+        assert_eq!(binomial(1_000_000, 2), Some(499999500000));
+    }
+
+    #[test]
+    fn picks_every_combination() {
+        use crate::{binomial, combinations};
+
+        for n in 0..8 {
+            let items = (0..n).collect::<Vec<u64>>();
+            for k in 0..=n + 1 {
+                let picked = combinations(&items, k as usize).collect::<Vec<_>>();
+                assert_eq!(
+                    picked.len() as u64,
+                    binomial(n, k).unwrap(),
+                    "{n} choose {k}"
+                );
+                assert!(picked.windows(2).all(|pair| pair[0] < pair[1]));
+                assert!(picked
+                    .iter()
+                    .all(|combination| combination.windows(2).all(|pair| pair[0] < pair[1])));
+            }
+        }
+        let nothing: Vec<&char> = vec![];
+        assert_eq!(combinations(&['a'], 0).collect::<Vec<_>>(), [nothing]);
+    }
+}
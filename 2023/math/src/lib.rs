@@ -0,0 +1,129 @@
+use std::{
+    fmt::Debug,
+    ops::{Add, Div, Mul, Rem, Sub},
+};
+
+mod combinatorics;
+mod modular;
+mod number;
+
+pub use combinatorics::{binomial, combinations, Combinations};
+pub use modular::{crt, extended_gcd, mod_inverse, mod_pow};
+pub use number::{gcd, isqrt, lcm, lcm_all};
+
+/// The primitive integers, so the helpers work on whatever the puzzle's numbers are parsed as.
+pub trait Int:
+    Copy
+    + Ord
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn checked_rem(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! int {
+    ($($int:ty)*) => {
+        $(
+            impl Int for $int {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$int>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$int>::checked_sub(self, other)
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$int>::checked_mul(self, other)
+                }
+
+                fn checked_rem(self, other: Self) -> Option<Self> {
+                    <$int>::checked_rem(self, other)
+                }
+            }
+        )*
+    };
+}
+
+int!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+/// `base` to the power of `exp`, or `None` if it doesn't fit.
+///
+/// ```
+/// assert_eq!(math::checked_pow(2usize, 10), Some(1024));
+/// assert_eq!(math::checked_pow(-3i64, 3), Some(-27));
+/// assert_eq!(math::checked_pow(2u8, 8), None);
+/// ```
+pub fn checked_pow<T: Int>(mut base: T, mut exp: usize) -> Option<T> {
+    let mut power = T::ONE;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            power = power.checked_mul(base)?;
+        }
+        exp >>= 1;
+        if exp > 0 {
+            base = base.checked_mul(base)?;
+        }
+    }
+    Some(power)
+}
+
+/// Sums and products that say so when they overflow, rather than wrapping in release builds.
+pub trait CheckedAccumulate<T: Int>: Iterator<Item = T> + Sized {
+    /// ```
+    /// use math::CheckedAccumulate;
+    /// assert_eq!([1u8, 2, 3].into_iter().checked_sum(), Some(6));
+    /// assert_eq!([200u8, 100].into_iter().checked_sum(), None);
+    /// ```
+    fn checked_sum(mut self) -> Option<T> {
+        self.try_fold(T::ZERO, T::checked_add)
+    }
+
+    fn checked_product(mut self) -> Option<T> {
+        self.try_fold(T::ONE, T::checked_mul)
+    }
+}
+
+impl<T: Int, I: Iterator<Item = T>> CheckedAccumulate<T> for I {}
+
+mod test {
+
+    #[test]
+    fn powers() {
+        use crate::checked_pow;
+
+        for exp in 0..64 {
+            assert_eq!(checked_pow(2u64, exp), Some(1 << exp));
+        }
+        assert_eq!(checked_pow(2u64, 64), None);
+        assert_eq!(checked_pow(0u32, 0), Some(1));
+        assert_eq!(checked_pow(0u32, 5), Some(0));
+        assert_eq!(checked_pow(10i128, 38), Some(10i128.pow(38)));
+        assert_eq!(checked_pow(10i128, 39), None);
+        // The last squaring would overflow, but we never need it.
+        assert_eq!(checked_pow(65536u32, 1), Some(65536));
+    }
+
+    #[test]
+    fn accumulates() {
+        use crate::CheckedAccumulate;
+
+        assert_eq!((1..=20u64).checked_product(), Some(2432902008176640000));
+        assert_eq!((1..=21u64).checked_product(), None);
+        assert_eq!(std::iter::empty::<i32>().checked_sum(), Some(0));
+        assert_eq!([i64::MIN, -1].into_iter().checked_sum(), None);
+    }
+}
@@ -0,0 +1,149 @@
+/// `base` to the power of `exp`, modulo `modulus`.
+///
+/// ```
+/// assert_eq!(math::mod_pow(4, 13, 497), 445);
+/// ```
+pub fn mod_pow(base: u64, mut exp: u64, modulus: u64) -> u64 {
+    assert!(modulus > 0, "can't work modulo 0");
+    // Squaring in u128 means nothing under the modulus can overflow.
+    let modulus = u128::from(modulus);
+    let mut base = u128::from(base) % modulus;
+    let mut power = 1 % modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            power = power * base % modulus;
+        }
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+    power as u64
+}
+
+/// `(g, x, y)` where `g` is the gcd of `a` and `b` and `a * x + b * y == g`.
+///
+/// ```
+/// let (g, x, y) = math::extended_gcd(240, 46);
+/// assert_eq!(g, 2);
+/// assert_eq!(240 * x + 46 * y, 2);
+/// ```
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// The number in `0..modulus` that multiplies with `a` to 1, if `a` and `modulus` are coprime.
+///
+/// ```
+/// assert_eq!(math::mod_inverse(3, 11), Some(4));
+/// assert_eq!(math::mod_inverse(4, 8), None);
+/// ```
+///
+/// # Panics
+///
+/// If `modulus` isn't positive.
+pub fn mod_inverse(a: i128, modulus: i128) -> Option<i128> {
+    assert!(modulus > 0, "can't work modulo {modulus}");
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+    (g == 1).then(|| x.rem_euclid(modulus))
+}
+
+/// Solves `x ≡ residue (mod modulus)` for every `(residue, modulus)` at once, with the Chinese
+/// remainder theorem.
+///
+/// Returns the smallest non-negative `x` along with the modulus it repeats at, the lcm of the
+/// moduli. They don't have to be coprime, but if they share factors the residues have to agree,
+/// otherwise there's no solution. It's also `None` if the working doesn't fit in an `i128`.
+///
+/// ```
+/// assert_eq!(math::crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+/// assert_eq!(math::crt(&[(1, 4), (2, 6)]), None);
+/// ```
+///
+/// # Panics
+///
+/// If a modulus isn't positive.
+pub fn crt(congruences: &[(i128, i128)]) -> Option<(i128, i128)> {
+    congruences
+        .iter()
+        .try_fold((0, 1), |(x, lcm), &(residue, modulus)| {
+            assert!(modulus > 0, "can't work modulo {modulus}");
+            let (g, inverse, _) = extended_gcd(lcm, modulus);
+            let difference = residue.checked_sub(x)?;
+            if difference % g != 0 {
+                return None;
+            }
+            // Step x by multiples of the lcm so far, which keeps the earlier congruences, until it
+            // satisfies this one too.
+            let step_modulus = modulus / g;
+            let steps = (difference / g % step_modulus)
+                .checked_mul(inverse.rem_euclid(step_modulus))?
+                .rem_euclid(step_modulus);
+            let next_lcm = lcm.checked_mul(step_modulus)?;
+            let next_x = x.checked_add(lcm.checked_mul(steps)?)?;
+            Some((next_x.rem_euclid(next_lcm), next_lcm))
+        })
+}
+
+mod test {
+
+    #[test]
+    fn powers() {
+        use crate::mod_pow;
+
+        assert_eq!(mod_pow(2, 10, 1000), 24);
+        assert_eq!(mod_pow(7, 0, 13), 1);
+        assert_eq!(mod_pow(7, 0, 1), 0);
+        // Fermat's little theorem, with a modulus whose squares overflow u64.
+        let prime = 18446744073709551557;
+        assert_eq!(mod_pow(123456789, prime - 1, prime), 1);
+    }
+
+    #[test]
+    fn inverses() {
+        use crate::mod_inverse;
+
+        for a in 1..13 {
+            let inverse = mod_inverse(a, 13).unwrap();
+            assert_eq!(a * inverse % 13, 1);
+        }
+        assert_eq!(mod_inverse(-3, 11), Some(7));
+        assert_eq!(mod_inverse(6, 9), None);
+    }
+
+    #[test]
+    fn chinese_remainders() {
+        use crate::crt;
+
+        assert_eq!(crt(&[]), Some((0, 1)));
+        assert_eq!(crt(&[(-1, 5)]), Some((4, 5)));
+        // Sharing a factor of 2, and agreeing on it.
+        assert_eq!(crt(&[(3, 4), (5, 6)]), Some((11, 12)));
+        let (x, modulus) = crt(&[(0, 17), (11, 13), (16, 19)]).unwrap();
+        assert_eq!(modulus, 17 * 13 * 19);
+        assert_eq!((x % 17, x % 13, x % 19), (0, 11, 16));
+
+        // Moduli that are fine on their own, but whose working doesn't fit.
+        let big = i128::MAX / 3;
+        assert_eq!(crt(&[(1, big), (2, big - 1)]), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "can't work modulo 0")]
+    fn chinese_remainders_need_positive_moduli() {
+        use crate::crt;
+
+        let _ = crt(&[(1, 3), (2, 0)]);
+    }
+}
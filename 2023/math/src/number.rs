@@ -0,0 +1,101 @@
+use crate::Int;
+
+/// The greatest common divisor, never negative, or `None` if it doesn't fit.
+///
+/// That's only when it's a signed type's `MIN`, the gcd of it and 0 or itself, as `-MIN` is one
+/// too big.
+///
+/// ```
+/// assert_eq!(math::gcd(12u32, 18), Some(6));
+/// assert_eq!(math::gcd(-4i64, 6), Some(2));
+/// assert_eq!(math::gcd(0u8, 0), Some(0));
+/// assert_eq!(math::gcd(i8::MIN, -1), Some(1));
+/// assert_eq!(math::gcd(i8::MIN, 0), None);
+/// ```
+pub fn gcd<T: Int>(mut a: T, mut b: T) -> Option<T> {
+    while b != T::ZERO {
+        // The one remainder that overflows is `MIN % -1`, which is 0 really.
+        (a, b) = (b, a.checked_rem(b).unwrap_or(T::ZERO));
+    }
+    if a < T::ZERO {
+        T::ZERO.checked_sub(a)
+    } else {
+        Some(a)
+    }
+}
+
+/// The least common multiple, or `None` if it doesn't fit.
+pub fn lcm<T: Int>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    let lcm = (a / gcd(a, b)?).checked_mul(b)?;
+    if lcm < T::ZERO {
+        T::ZERO.checked_sub(lcm)
+    } else {
+        Some(lcm)
+    }
+}
+
+/// The least common multiple of all of them, which is 1 if there aren't any.
+///
+/// ```
+/// assert_eq!(math::lcm_all([4u64, 6, 10]), Some(60));
+/// ```
+pub fn lcm_all<T: Int>(numbers: impl IntoIterator<Item = T>) -> Option<T> {
+    numbers
+        .into_iter()
+        .try_fold(T::ONE, |lcm_so_far, number| lcm(lcm_so_far, number))
+}
+
+/// The largest number whose square is at most `n`.
+///
+/// ```
+/// assert_eq!(math::isqrt(24), 4);
+/// assert_eq!(math::isqrt(25), 5);
+/// ```
+pub fn isqrt(n: u64) -> u64 {
+    // A float gets us within a few of the answer, and u128 lets us square our way the rest.
+    let n = u128::from(n);
+    let mut root = (n as f64).sqrt() as u128;
+    while root * root > n {
+        root -= 1;
+    }
+    while (root + 1) * (root + 1) <= n {
+        root += 1;
+    }
+    root as u64
+}
+
+mod test {
+
+    #[test]
+    fn divisors() {
+        use crate::{gcd, lcm, lcm_all};
+
+        assert_eq!(gcd(17u64, 5), Some(1));
+        assert_eq!(gcd(0i32, -7), Some(7));
+        assert_eq!(gcd(i64::MIN, i64::MIN / 2), Some(-(i64::MIN / 2)));
+        assert_eq!(gcd(i64::MIN, i64::MIN), None);
+        assert_eq!(lcm(i64::MIN, 1), None);
+        assert_eq!(lcm(i64::MIN / 2, 2), Some(-(i64::MIN / 2)));
+        assert_eq!(lcm(-4i32, 6), Some(12));
+        assert_eq!(lcm(0u8, 3), Some(0));
+        assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+        assert_eq!(lcm_all(std::iter::empty::<u8>()), Some(1));
+        assert_eq!(lcm_all(1..=20u64), Some(232792560));
+    }
+
+    #[test]
+    fn square_roots() {
+        use crate::isqrt;
+
+        for n in 0..10_000 {
+            let root = isqrt(n);
+            assert!(root * root <= n && (root + 1) * (root + 1) > n, "{n}");
+        }
+        assert_eq!(isqrt(u64::MAX), u32::MAX.into());
+        assert_eq!(isqrt((1 << 52) + 1), 1 << 26);
+        assert_eq!(isqrt(999_999_999_999_999_999), 999_999_999);
+    }
+}
@@ -0,0 +1,201 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// An example from the puzzle description, and the answers it's supposed to give.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Example {
+    pub input: String,
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+/// Pulls the examples out of a saved puzzle page.
+///
+/// Each part is its own `<article class="day-desc">`. We take the first `<pre><code>` block of an
+/// article as its example and the last `<code><em>` as its answer, which is how the puzzles are
+/// written up. Part two often reuses part one's example, in which case it has no block of its own.
+pub fn extract_examples(html: &str) -> Vec<Example> {
+    let mut examples: Vec<Example> = vec![];
+
+    for (part, article) in articles(html).into_iter().enumerate().take(2) {
+        let answer = last_between(article, "<code><em>", "</em></code>").map(decode);
+        let input = first_between(article, "<pre><code>", "</code></pre>")
+            .map(|block| decode(&strip_tags(block)).trim_end().to_string());
+
+        match input {
+            Some(input) => examples.push(Example {
+                input,
+                part_one: None,
+                part_two: None,
+            }),
+            None if examples.is_empty() => continue,
+            None => {}
+        }
+        let example = examples.last_mut().unwrap();
+        match part {
+            0 => example.part_one = answer,
+            _ => example.part_two = answer,
+        }
+    }
+
+    examples
+}
+
+fn articles(html: &str) -> Vec<&str> {
+    html.split("<article class=\"day-desc\">")
+        .skip(1)
+        .map(|article| article.split("</article>").next().unwrap_or(article))
+        .collect()
+}
+
+fn first_between<'a>(haystack: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let (_, rest) = haystack.split_once(start)?;
+    rest.split_once(end).map(|(between, _)| between)
+}
+
+fn last_between<'a>(haystack: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    let (_, rest) = haystack.rsplit_once(start)?;
+    rest.split_once(end).map(|(between, _)| between)
+}
+
+/// Examples sometimes `<em>`phasize the interesting bits, which we don't want in the input.
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+fn decode(html: &str) -> String {
+    html.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Writes `examples/<n>.txt` for each example's input, and `examples/<n>.part_one.expected` and
+/// `examples/<n>.part_two.expected` for the answers we found, returning the files we wrote.
+/// `aoc_macros::example_tests!` turns those into tests.
+///
+/// The inputs are written without a trailing newline, same as our `input.txt`s.
+pub fn write_fixtures(crate_dir: &Path, examples: &[Example]) -> io::Result<Vec<PathBuf>> {
+    let dir = crate_dir.join("examples");
+    fs::create_dir_all(&dir)?;
+
+    let mut written = vec![];
+    for (index, example) in examples.iter().enumerate() {
+        let name = index + 1;
+        let files = [
+            (format!("{name}.txt"), Some(&example.input)),
+            (
+                format!("{name}.part_one.expected"),
+                example.part_one.as_ref(),
+            ),
+            (
+                format!("{name}.part_two.expected"),
+                example.part_two.as_ref(),
+            ),
+        ];
+        for (file, contents) in files {
+            if let Some(contents) = contents {
+                fs::write(dir.join(&file), contents)?;
+                written.push(dir.join(file));
+            }
+        }
+    }
+    Ok(written)
+}
+
+mod test {
+    /// Trimmed down from the real day 4 page, after solving part one.
+    #[allow(dead_code)]
+    const DAY_FOUR: &str = r#"<main>
+<article class="day-desc"><h2>--- Day 4: Scratchcards ---</h2><p>For example:</p>
+<pre><code>Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+</code></pre>
+<p>In the above example, card 1 has five winning numbers (<code>41</code>, <code>48</code>) and eight numbers you have; card 1 is worth <code><em>8</em></code> points.</p>
+<p>So, in this example, the Elf's pile of scratchcards is worth <code><em>13</em></code> points.</p>
+</article>
+<p>Your puzzle answer was <code>25004</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>Once all of the originals and copies have been processed, you end up with <code><em>30</em></code> scratchcards.</p>
+</article>
+</main>"#;
+
+    #[test]
+    fn part_two_reuses_part_one_example() {
+        use crate::extract::{extract_examples, Example};
+
+        assert_eq!(
+            extract_examples(DAY_FOUR),
+            vec![Example {
+                input: "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53\nCard 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19".into(),
+                part_one: Some("13".into()),
+                part_two: Some("30".into()),
+            }]
+        );
+    }
+
+    #[test]
+    fn part_two_has_its_own_example() {
+        use crate::extract::{extract_examples, Example};
+
+        let html = r#"<article class="day-desc"><pre><code>1abc2
+<em>pqr3stu8vwx</em>
+</code></pre><p>Adding these together produces <code><em>142</em></code>.</p></article>
+<article class="day-desc"><pre><code>two1nine
+a&lt;b&amp;c
+</code></pre><p>Adding these together produces <code><em>281</em></code>.</p></article>"#;
+
+        assert_eq!(
+            extract_examples(html),
+            vec![
+                Example {
+                    input: "1abc2\npqr3stu8vwx".into(),
+                    part_one: Some("142".into()),
+                    part_two: None,
+                },
+                Example {
+                    input: "two1nine\na<b&c".into(),
+                    part_one: None,
+                    part_two: Some("281".into()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn writes_fixtures() {
+        use crate::extract::{extract_examples, write_fixtures};
+        use std::fs;
+
+        let crate_dir = std::env::temp_dir().join(format!("runner-extract-{}", std::process::id()));
+        let written = write_fixtures(&crate_dir, &extract_examples(DAY_FOUR)).unwrap();
+        let contents = written
+            .iter()
+            .map(|path| fs::read_to_string(path).unwrap())
+            .collect::<Vec<_>>();
+        fs::remove_dir_all(&crate_dir).unwrap();
+
+        assert_eq!(
+            written,
+            vec![
+                crate_dir.join("examples/1.txt"),
+                crate_dir.join("examples/1.part_one.expected"),
+                crate_dir.join("examples/1.part_two.expected"),
+            ]
+        );
+        assert_eq!(contents[1..], ["13", "30"]);
+    }
+}
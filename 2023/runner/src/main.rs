@@ -0,0 +1,346 @@
+use std::{
+    ops::RangeInclusive,
+    path::PathBuf,
+    process::{Command as Process, Stdio},
+    time::Duration,
+};
+
+use aoc::input::Input;
+use clap::{Args, Parser, Subcommand};
+
+mod extract;
+mod progress;
+mod registry;
+mod solutions;
+mod submit;
+mod watch;
+
+#[derive(Parser)]
+#[command(about = "Runs and inspects our Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// Which solution to run.
+#[derive(Args)]
+struct PuzzlePart {
+    year: u16,
+    day: u8,
+    part: u8,
+    /// Read the puzzle input from here instead of the day's input.txt.
+    #[arg(long)]
+    input: Option<PathBuf>,
+}
+
+impl PuzzlePart {
+    fn solution(&self) -> &'static solutions::Solution {
+        solutions::find(self.year, self.day, self.part).unwrap_or_else(|| {
+            panic!(
+                "no solution for {} day {} part {}",
+                self.year, self.day, self.part
+            )
+        })
+    }
+
+    fn read_input(&self) -> Input {
+        let path = self
+            .input
+            .clone()
+            .unwrap_or_else(|| self.solution().input_path());
+        Input::read(&path)
+            .unwrap_or_else(|error| panic!("couldn't read {}: {error}", path.display()))
+    }
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Print the answer for the puzzle input.
+    Run {
+        #[command(flatten)]
+        puzzle: PuzzlePart,
+    },
+    /// Print the answers for both of a day's parts, parsing the input once if they share a
+    /// generator.
+    Day {
+        year: u16,
+        day: u8,
+        /// Read the puzzle input from here instead of the day's input.txt.
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Ask a question about 2023 day two's games, like `sum(power) where game % 2 == 0`.
+    ///
+    /// Queries can name `games`, per game `game`, `hands` and `power`, and per hand `hand`, `red`,
+    /// `green`, `blue` and `cubes`, and go over the hands or games with `sum`, `product`, `max`,
+    /// `min`, `count`, `any` and `all`, or `any_hand`, `all_hands`, `any_game` and `all_games`.
+    Games {
+        query: String,
+        /// Read the puzzle input from here instead of the day's input.txt.
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Check a day's input for lines that don't parse, and ones that parse but aren't what the
+    /// puzzle says they'll be, like gaps in the numbering.
+    ///
+    /// Exits with an error if any lines don't parse, but not for the warnings.
+    Validate {
+        year: u16,
+        day: u8,
+        /// Read the puzzle input from here instead of the day's input.txt.
+        #[arg(long)]
+        input: Option<PathBuf>,
+    },
+    /// Show what each line of the input contributed to the answer.
+    Explain {
+        #[command(flatten)]
+        puzzle: PuzzlePart,
+        /// Only show these lines, e.g. `7` or `3-10`.
+        #[arg(long, value_parser = parse_lines)]
+        lines: Option<RangeInclusive<usize>>,
+    },
+    /// Turn the examples in a saved puzzle page into fixtures for a day's crate.
+    Extract {
+        /// The puzzle description, saved from the browser.
+        page: PathBuf,
+        /// The day's crate, the fixtures go in its examples/.
+        crate_dir: PathBuf,
+    },
+    /// Re-run a part's tests and then its real input every time the day's crate changes.
+    Watch {
+        year: u16,
+        day: u8,
+        part: u8,
+        /// How long to wait for saves to settle down before re-running, in milliseconds.
+        #[arg(long, default_value_t = 300)]
+        debounce: u64,
+    },
+    /// Show which parts of each year are verified, implemented, failing or still stubbed out.
+    Progress,
+    /// Submit an answer, unless it's one we already know is wrong.
+    ///
+    /// Needs the `session` cookie of a logged in browser in `AOC_SESSION`. `AOC_SERVER` points it
+    /// somewhere other than adventofcode.com.
+    Submit {
+        #[command(flatten)]
+        puzzle: PuzzlePart,
+        /// Submit this instead of what the solution gives.
+        #[arg(long)]
+        answer: Option<String>,
+    },
+}
+
+fn parse_lines(lines: &str) -> Result<RangeInclusive<usize>, String> {
+    let parse = |line: &str| {
+        line.trim()
+            .parse::<usize>()
+            .map_err(|error| format!("{line:?} isn't a line number: {error}"))
+    };
+    match lines.split_once('-') {
+        Some((from, to)) => Ok(parse(from)?..=parse(to)?),
+        None => parse(lines).map(|line| line..=line),
+    }
+}
+
+/// Runs the part's tests, and if they pass its real input, through cargo so we pick up the edits.
+///
+/// Returns the answer, or `None` if the tests or the solution failed.
+fn run_through_cargo(solution: &solutions::Solution) -> Option<String> {
+    let workspace = solutions::repo_root().join(solution.year.to_string());
+
+    println!(
+        "running {} {} tests",
+        solution.package,
+        solution.test_filter()
+    );
+    let tests = Process::new("cargo")
+        .args(["test", "-q", "-p", solution.package, solution.test_filter()])
+        .current_dir(&workspace)
+        .status()
+        .expect("couldn't run cargo test");
+    if !tests.success() {
+        return None;
+    }
+
+    let output = Process::new("cargo")
+        .args(["run", "-q", "-p", "runner", "--", "run"])
+        .args([solution.year, solution.day.into(), solution.part.into()].map(|arg| arg.to_string()))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .stderr(Stdio::inherit())
+        .output()
+        .expect("couldn't run cargo run");
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+fn main() {
+    match Cli::parse().command {
+        Command::Run { puzzle } => match puzzle.solution().solve(&puzzle.read_input()) {
+            Ok(answer) => println!("{answer}"),
+            Err(error) => {
+                eprintln!("{error}");
+                std::process::exit(1);
+            }
+        },
+        Command::Day { year, day, input } => {
+            let path = input.unwrap_or_else(|| {
+                solutions::find(year, day, 1)
+                    .unwrap_or_else(|| panic!("no solutions for {year} day {day}"))
+                    .input_path()
+            });
+            let input = Input::read(&path)
+                .unwrap_or_else(|error| panic!("couldn't read {}: {error}", path.display()));
+            let mut failed = false;
+            for (part, answer) in solutions::solve_day(year, day, &input) {
+                match answer {
+                    Ok(answer) => println!("part {part}: {answer}"),
+                    Err(error) => {
+                        eprintln!("part {part}: {error}");
+                        failed = true;
+                    }
+                }
+            }
+            if failed {
+                std::process::exit(1);
+            }
+        }
+        Command::Games { query, input } => {
+            let parsed = query.parse::<day_two::query::Query>();
+            let answer = parsed.and_then(|parsed| {
+                let path = input.unwrap_or_else(|| {
+                    solutions::find(2023, 2, 1)
+                        .expect("2023 day two is registered")
+                        .input_path()
+                });
+                let input = Input::read(&path)
+                    .unwrap_or_else(|error| panic!("couldn't read {}: {error}", path.display()));
+                parsed.run(&input)
+            });
+            match answer {
+                Ok(answer) => println!("{answer}"),
+                Err(error) => {
+                    eprintln!("{}", error.show(&query));
+                    std::process::exit(1);
+                }
+            }
+        }
+        Command::Validate { year, day, input } => {
+            let validator = aoc::validate::find(year, day)
+                .unwrap_or_else(|| panic!("{year} day {day} has no #[aoc_validator]"));
+            let path = input.unwrap_or_else(|| {
+                solutions::find(year, day, 1)
+                    .unwrap_or_else(|| panic!("no solutions for {year} day {day}"))
+                    .input_path()
+            });
+            let input = Input::read(&path)
+                .unwrap_or_else(|error| panic!("couldn't read {}: {error}", path.display()));
+            let report = (validator.validate)(&input);
+            println!("{report}");
+            if !report.errors.is_empty() {
+                std::process::exit(1);
+            }
+        }
+        Command::Extract { page, crate_dir } => {
+            let html = std::fs::read_to_string(&page)
+                .unwrap_or_else(|error| panic!("couldn't read {}: {error}", page.display()));
+            let examples = extract::extract_examples(&html);
+            if examples.is_empty() {
+                panic!("no examples in {}", page.display());
+            }
+            for path in extract::write_fixtures(&crate_dir, &examples).unwrap() {
+                println!("wrote {}", path.display());
+            }
+        }
+        Command::Watch {
+            year,
+            day,
+            part,
+            debounce,
+        } => {
+            let solution = solutions::find(year, day, part)
+                .unwrap_or_else(|| panic!("no solution for {year} day {day} part {part}"));
+            let mut watcher = watch::Watcher::new(
+                vec![solution.crate_dir.into(), solution.input_path()],
+                Duration::from_millis(debounce),
+            );
+
+            let mut last_answer = None;
+            loop {
+                match run_through_cargo(solution) {
+                    Some(answer) => {
+                        println!(
+                            "answer: {}",
+                            watch::answer_diff(last_answer.as_deref(), &answer)
+                        );
+                        last_answer = Some(answer);
+                    }
+                    None => println!(
+                        "failed, last answer: {}",
+                        last_answer.as_deref().unwrap_or("none")
+                    ),
+                }
+
+                println!("watching {}", solution.crate_dir);
+                for path in watcher.wait_for_change() {
+                    println!("changed: {}", path.display());
+                }
+            }
+        }
+        Command::Progress => {
+            let registry =
+                registry::Registry::load(&solutions::repo_root().join("answers/registry.txt"))
+                    .unwrap();
+            // The panics are reported in the calendar, we don't need them twice.
+            std::panic::set_hook(Box::new(|_| {}));
+            let progress = progress::Progress::check(&solutions::solutions(), &registry);
+            let _ = std::panic::take_hook();
+            print!("{progress}");
+        }
+        Command::Submit { puzzle, answer } => {
+            let answer = answer.unwrap_or_else(|| {
+                puzzle
+                    .solution()
+                    .solve(&puzzle.read_input())
+                    .unwrap_or_else(|error| panic!("{error}"))
+                    .to_line()
+            });
+            let client = submit::Client {
+                server: std::env::var("AOC_SERVER")
+                    .unwrap_or_else(|_| "https://adventofcode.com".into()),
+                session: std::env::var("AOC_SESSION").expect("AOC_SESSION isn't set"),
+            };
+            let answers = solutions::repo_root().join("answers");
+            let files = submit::AnswerFiles {
+                registry: answers.join("registry.txt"),
+                history: answers.join("history.txt"),
+            };
+            let part = (puzzle.year, puzzle.day, puzzle.part);
+            match client.submit(&files, part, &answer) {
+                Ok(submit::Outcome::Correct) => {
+                    println!("{answer} is right, added it to the registry");
+                }
+                Ok(submit::Outcome::Unknown(text)) => println!("{answer}: {text}"),
+                Ok(outcome) => println!("{answer} was {outcome}"),
+                Err(reason) => {
+                    eprintln!("not submitting {answer}: {reason}");
+                    std::process::exit(1);
+                }
+            }
+        }
+        Command::Explain { puzzle, lines } => {
+            let explain = puzzle.solution().explain.unwrap_or_else(|| {
+                panic!(
+                    "{} day {} part {} can't explain itself yet",
+                    puzzle.year, puzzle.day, puzzle.part
+                )
+            });
+            let explanation = explain(&puzzle.read_input());
+            match lines {
+                Some(lines) => println!("{}", explanation.lines(lines)),
+                None => println!("{explanation}"),
+            }
+        }
+    }
+}
@@ -0,0 +1,218 @@
+use std::{
+    collections::BTreeMap,
+    fmt,
+    panic::{self, AssertUnwindSafe},
+};
+
+use aoc::{answer::Answer, input::Input, solution::SolveError};
+
+use crate::{
+    registry::{PartId, Registry},
+    solutions::Solution,
+};
+
+/// How far along a part is.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Status {
+    /// Gives the answer in the registry.
+    Verified,
+    /// Runs, but there's no registered answer to check it against, or no input to run it on.
+    Implemented,
+    /// Disagrees with the registry, overflows, or panics.
+    Failing(String),
+    /// Still a `todo!()`.
+    Stubbed,
+}
+
+impl Status {
+    fn symbol(&self) -> char {
+        match self {
+            Status::Verified => '*',
+            Status::Implemented => '+',
+            Status::Failing(_) => '!',
+            Status::Stubbed => '~',
+        }
+    }
+}
+
+/// Runs `solve` on the input, if we have it, and compares it to the registered answer.
+///
+/// Panics are caught, so a `todo!()` shows up as [`Status::Stubbed`] rather than taking us down.
+pub fn check(
+    solve: impl Fn(&str) -> Result<Answer, SolveError>,
+    input: Option<&str>,
+    expected: Option<&str>,
+) -> Status {
+    let Some(input) = input else {
+        return Status::Implemented;
+    };
+    let answer = match panic::catch_unwind(AssertUnwindSafe(|| solve(input))) {
+        Ok(Ok(answer)) => answer,
+        Ok(Err(error)) => return Status::Failing(error.to_string()),
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_default();
+            return if message.starts_with("not yet implemented") {
+                Status::Stubbed
+            } else {
+                Status::Failing(format!("panicked: {message}"))
+            };
+        }
+    };
+    match expected {
+        Some(expected) if answer == expected => Status::Verified,
+        Some(expected) => {
+            Status::Failing(format!("gave {}, expected {expected}", answer.to_line()))
+        }
+        None => Status::Implemented,
+    }
+}
+
+/// Every part we have a solution for, by year.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Progress {
+    pub years: BTreeMap<u16, BTreeMap<(u8, u8), Status>>,
+}
+
+impl Progress {
+    /// Runs every solution on its input and checks it against the registry.
+    pub fn check(solutions: &[&Solution], registry: &Registry) -> Self {
+        let mut progress = Self::default();
+        for solution in solutions {
+            let id: PartId = (solution.year, solution.day, solution.part);
+            let input = Input::read(&solution.input_path()).ok();
+            progress.insert(
+                id,
+                check(
+                    |input| solution.solve(input),
+                    input.as_deref(),
+                    registry.get(id),
+                ),
+            );
+        }
+        progress
+    }
+
+    pub fn insert(&mut self, (year, day, part): PartId, status: Status) {
+        self.years
+            .entry(year)
+            .or_default()
+            .insert((day, part), status);
+    }
+}
+
+impl fmt::Display for Progress {
+    /// A calendar per year, each day showing how its two parts are going, then what's failing.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (year, parts) in &self.years {
+            writeln!(f, "{year}")?;
+            for week in (1..=25).collect::<Vec<u8>>().chunks(5) {
+                let days = week
+                    .iter()
+                    .map(|day| {
+                        let [part_one, part_two] =
+                            [1, 2].map(|part| parts.get(&(*day, part)).map_or('.', Status::symbol));
+                        format!("{day:>2} {part_one}{part_two}")
+                    })
+                    .collect::<Vec<_>>();
+                writeln!(f, "{}", days.join("   "))?;
+            }
+            writeln!(f)?;
+        }
+        writeln!(
+            f,
+            "* verified   + implemented   ! failing   ~ stubbed   . not started"
+        )?;
+
+        for (year, parts) in &self.years {
+            for ((day, part), status) in parts {
+                if let Status::Failing(reason) = status {
+                    writeln!(f, "{year} day {day} part {part}: {reason}")?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+mod test {
+
+    #[test]
+    fn checks_solutions() {
+        use crate::progress::{check, Status};
+        use aoc::{
+            answer::{Answer, Operation, Overflow},
+            solution::SolveError,
+        };
+
+        let solve: fn(&str) -> Result<Answer, SolveError> =
+            |input| Ok(input.lines().count().into());
+        assert_eq!(check(solve, Some("a\nb"), Some("2")), Status::Verified);
+        assert_eq!(check(solve, Some("a\nb"), None), Status::Implemented);
+        assert_eq!(check(solve, None, Some("2")), Status::Implemented);
+        assert_eq!(
+            check(solve, Some("a"), Some("2")),
+            Status::Failing("gave 1, expected 2".into())
+        );
+        assert_eq!(
+            check(
+                |_| Err(SolveError::Overflow(Overflow {
+                    operation: Operation::Add,
+                    lhs: u128::MAX.into(),
+                    rhs: 1u8.into()
+                })),
+                Some(""),
+                None
+            ),
+            Status::Failing(
+                "the answer overflowed: 340282366920938463463374607431768211455 + 1 doesn't fit \
+                 in a u128"
+                    .into()
+            )
+        );
+        assert_eq!(check(|_| todo!(), Some(""), None), Status::Stubbed);
+        assert_eq!(
+            check(|_| panic!("bad line {}", 3), Some(""), None),
+            Status::Failing("panicked: bad line 3".into())
+        );
+    }
+
+    #[test]
+    fn renders_calendar() {
+        use crate::progress::{Progress, Status};
+
+        let mut progress = Progress::default();
+        progress.insert((2020, 1, 1), Status::Verified);
+        progress.insert((2020, 1, 2), Status::Verified);
+        progress.insert((2020, 2, 1), Status::Stubbed);
+        progress.insert((2020, 2, 2), Status::Stubbed);
+        progress.insert((2023, 3, 1), Status::Implemented);
+        progress.insert((2023, 4, 1), Status::Verified);
+        progress.insert(
+            (2023, 4, 2),
+            Status::Failing("gave 30, expected 14427616".into()),
+        );
+
+        insta::assert_snapshot!(progress.to_string(), @"
+        2020
+         1 **    2 ~~    3 ..    4 ..    5 ..
+         6 ..    7 ..    8 ..    9 ..   10 ..
+        11 ..   12 ..   13 ..   14 ..   15 ..
+        16 ..   17 ..   18 ..   19 ..   20 ..
+        21 ..   22 ..   23 ..   24 ..   25 ..
+
+        2023
+         1 ..    2 ..    3 +.    4 *!    5 ..
+         6 ..    7 ..    8 ..    9 ..   10 ..
+        11 ..   12 ..   13 ..   14 ..   15 ..
+        16 ..   17 ..   18 ..   19 ..   20 ..
+        21 ..   22 ..   23 ..   24 ..   25 ..
+
+        * verified   + implemented   ! failing   ~ stubbed   . not started
+        2023 day 4 part 2: gave 30, expected 14427616
+        ");
+    }
+}
@@ -0,0 +1,120 @@
+use std::{collections::BTreeMap, fs, io, path::Path};
+
+/// A puzzle part, as `(year, day, part)`.
+pub type PartId = (u16, u8, u8);
+
+/// The answers we know are right, one `year day part answer` line each.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Registry {
+    answers: BTreeMap<PartId, String>,
+}
+
+impl Registry {
+    /// Reads the registry, which is fine not existing yet.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Self::parse(&contents),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(error),
+        }
+    }
+
+    fn parse(contents: &str) -> io::Result<Self> {
+        let answers = contents
+            .lines()
+            .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+            .map(|line| {
+                parse_line(line).ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("bad registry line: {line:?}"),
+                    )
+                })
+            })
+            .collect::<io::Result<_>>()?;
+        Ok(Self { answers })
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut contents = String::from("# year\tday\tpart\tanswer\n");
+        for ((year, day, part), answer) in &self.answers {
+            contents.push_str(&format!("{year}\t{day}\t{part}\t{answer}\n"));
+        }
+        fs::write(path, contents)
+    }
+
+    pub fn get(&self, part: PartId) -> Option<&str> {
+        self.answers.get(&part).map(String::as_str)
+    }
+
+    pub fn insert(&mut self, part: PartId, answer: String) {
+        self.answers.insert(part, answer);
+    }
+
+    #[cfg(test)]
+    pub fn iter(&self) -> impl Iterator<Item = (PartId, &str)> {
+        self.answers
+            .iter()
+            .map(|(part, answer)| (*part, answer.as_str()))
+    }
+}
+
+fn parse_line(line: &str) -> Option<(PartId, String)> {
+    let mut fields = line.split('\t');
+    let year = fields.next()?.parse().ok()?;
+    let day = fields.next()?.parse().ok()?;
+    let part = fields.next()?.parse().ok()?;
+    let answer = fields.next()?.to_string();
+    fields
+        .next()
+        .is_none()
+        .then_some(((year, day, part), answer))
+}
+
+mod test {
+    #[test]
+    fn round_trips() {
+        use crate::registry::Registry;
+
+        let dir = std::env::temp_dir().join(format!("runner-registry-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("registry.txt");
+
+        assert_eq!(Registry::load(&path).unwrap(), Registry::default());
+
+        let mut registry = Registry::default();
+        registry.insert((2023, 4, 2), "14427616".into());
+        registry.insert((2023, 1, 1), "54630".into());
+        registry.save(&path).unwrap();
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let loaded = Registry::load(&path).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            contents,
+            "# year\tday\tpart\tanswer\n2023\t1\t1\t54630\n2023\t4\t2\t14427616\n"
+        );
+        assert_eq!(loaded, registry);
+    }
+
+    /// The registry is where the answers our tests check live, so make sure they still agree.
+    #[test]
+    fn solutions_give_registered_answers() {
+        use crate::{registry::Registry, solutions};
+        use aoc::input::Input;
+
+        let registry =
+            Registry::load(&solutions::repo_root().join("answers/registry.txt")).unwrap();
+        for ((year, day, part), answer) in registry.iter() {
+            let Some(solution) = solutions::find(year, day, part) else {
+                continue;
+            };
+            let input = Input::read(&solution.input_path()).unwrap();
+            assert_eq!(
+                solution.solve(&input).unwrap(),
+                answer,
+                "{year} day {day} part {part}"
+            );
+        }
+    }
+}
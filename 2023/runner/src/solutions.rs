@@ -0,0 +1,66 @@
+use std::path::PathBuf;
+
+pub use aoc::solution::{find, solutions, solve_day, Solution};
+
+// The solutions register themselves with `#[aoc]`, but only if their crates get linked in, which
+// takes using them.
+use {
+    day_4 as _, day_one as _, day_one_2020 as _, day_three as _, day_two as _, day_two_2020 as _,
+};
+
+/// The root of the repo, which holds a workspace per year.
+pub fn repo_root() -> PathBuf {
+    let root = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../..");
+    root.canonicalize().unwrap_or(root)
+}
+
+mod test {
+
+    #[test]
+    fn every_day_registers_its_parts() {
+        use crate::solutions::solutions;
+
+        let registered = solutions()
+            .iter()
+            .map(|solution| (solution.year, solution.day, solution.part, solution.package))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            registered,
+            [
+                (2020, 1, 1, "day-one-2020"),
+                (2020, 1, 2, "day-one-2020"),
+                (2020, 2, 1, "day-two-2020"),
+                (2020, 2, 2, "day-two-2020"),
+                (2023, 1, 1, "day-one"),
+                (2023, 1, 2, "day-one"),
+                (2023, 2, 1, "day-two"),
+                (2023, 2, 2, "day-two"),
+                (2023, 3, 1, "day-three"),
+                (2023, 4, 1, "day-4"),
+                (2023, 4, 2, "day-4"),
+            ]
+        );
+        assert_eq!(aoc::validate::validated_days(), [(2023, 2), (2023, 4)]);
+        let input = crate::solutions::find(2020, 1, 1).unwrap().input_path();
+        assert!(input.ends_with("2020/day-one/input"), "{}", input.display());
+    }
+
+    #[test]
+    fn generated_input_is_shared_by_both_parts() {
+        use crate::solutions::{find, solve_day};
+
+        let input = "1721\n979\n366\n299\n675\n1456";
+        let answers = solve_day(2020, 1, input)
+            .into_iter()
+            .map(|(part, answer)| (part, answer.unwrap().to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            answers,
+            [(1, "514579".to_string()), (2, "241861950".to_string())]
+        );
+        assert_eq!(
+            find(2020, 1, 2).unwrap().solve(input).unwrap(),
+            241861950u64
+        );
+    }
+}
@@ -0,0 +1,583 @@
+use std::{
+    fmt, fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::registry::{PartId, Registry};
+
+/// What the site said about an answer.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint which way.
+    Wrong,
+    /// We submitted too recently and have to wait this long to try again.
+    RateLimited {
+        wait: Duration,
+    },
+    /// The part is already solved, or part one isn't yet.
+    WrongLevel,
+    /// A page we don't know how to read, with the text we found in it.
+    Unknown(String),
+}
+
+impl Outcome {
+    fn is_wrong(&self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+
+    fn parse(field: &str) -> Option<Self> {
+        Some(match field {
+            "correct" => Outcome::Correct,
+            "too-high" => Outcome::TooHigh,
+            "too-low" => Outcome::TooLow,
+            "wrong" => Outcome::Wrong,
+            "wrong-level" => Outcome::WrongLevel,
+            "unknown" => Outcome::Unknown(String::new()),
+            field => Outcome::RateLimited {
+                wait: Duration::from_secs(field.strip_prefix("rate-limited:")?.parse().ok()?),
+            },
+        })
+    }
+}
+
+impl fmt::Display for Outcome {
+    /// How outcomes are written in the history file.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "correct"),
+            Outcome::TooHigh => write!(f, "too-high"),
+            Outcome::TooLow => write!(f, "too-low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::RateLimited { wait } => write!(f, "rate-limited:{}", wait.as_secs()),
+            Outcome::WrongLevel => write!(f, "wrong-level"),
+            Outcome::Unknown(_) => write!(f, "unknown"),
+        }
+    }
+}
+
+/// Reads the outcome out of the page the site responds to a submission with.
+pub fn parse_response(html: &str) -> Outcome {
+    let text = html
+        .split_once("<article>")
+        .and_then(|(_, article)| article.split_once("</article>"))
+        .map_or(html, |(article, _)| article);
+
+    if text.contains("That's the right answer") {
+        Outcome::Correct
+    } else if text.contains("your answer is too high") {
+        Outcome::TooHigh
+    } else if text.contains("your answer is too low") {
+        Outcome::TooLow
+    } else if text.contains("That's not the right answer") {
+        Outcome::Wrong
+    } else if text.contains("You gave an answer too recently") {
+        Outcome::RateLimited {
+            wait: parse_wait(text).unwrap_or(Duration::from_secs(60)),
+        }
+    } else if text.contains("You don't seem to be solving the right level") {
+        Outcome::WrongLevel
+    } else {
+        Outcome::Unknown(strip_tags(text).trim().to_string())
+    }
+}
+
+/// Finds the `1m 30s` in "You have 1m 30s left to wait".
+fn parse_wait(text: &str) -> Option<Duration> {
+    let (before, _) = text.split_once(" left to wait")?;
+    let (_, wait) = before.rsplit_once("You have ")?;
+    wait.split_whitespace()
+        .map(|part| {
+            let (amount, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+            let amount: u64 = amount.parse().ok()?;
+            match unit {
+                "h" => Some(amount * 60 * 60),
+                "m" => Some(amount * 60),
+                "s" => Some(amount),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+/// One answer we sent off, and what came back.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Attempt {
+    pub part: PartId,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// Seconds since the unix epoch.
+    pub at: u64,
+}
+
+/// Why we won't send an answer off.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadySolved {
+        answer: String,
+    },
+    AlreadyTried {
+        outcome: Outcome,
+    },
+    /// A previous answer at or below this was too high.
+    TooHigh {
+        bound: i128,
+    },
+    /// A previous answer at or above this was too low.
+    TooLow {
+        bound: i128,
+    },
+    RateLimited {
+        remaining: Duration,
+    },
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::AlreadySolved { answer } => {
+                write!(f, "already solved, the answer is {answer}")
+            }
+            Refusal::AlreadyTried { outcome } => write!(f, "already tried that, it was {outcome}"),
+            Refusal::TooHigh { bound } => write!(f, "{bound} was already too high"),
+            Refusal::TooLow { bound } => write!(f, "{bound} was already too low"),
+            Refusal::RateLimited { remaining } => {
+                write!(f, "rate limited for another {}s", remaining.as_secs())
+            }
+        }
+    }
+}
+
+/// Every answer we've submitted, one tab separated `year day part answer outcome at` line each.
+#[derive(Debug, Default)]
+pub struct History {
+    pub attempts: Vec<Attempt>,
+}
+
+impl History {
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(error) => return Err(error),
+        };
+        let attempts = contents
+            .lines()
+            .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+            .map(|line| {
+                parse_attempt(line).ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("bad history line: {line:?}"),
+                    )
+                })
+            })
+            .collect::<io::Result<_>>()?;
+        Ok(Self { attempts })
+    }
+
+    /// Appends the attempt to the file as well as to us.
+    pub fn record(&mut self, path: &Path, attempt: Attempt) -> io::Result<()> {
+        let is_new = !path.exists();
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?;
+        if is_new {
+            writeln!(file, "# year\tday\tpart\tanswer\toutcome\tat")?;
+        }
+        let (year, day, part) = attempt.part;
+        writeln!(
+            file,
+            "{year}\t{day}\t{part}\t{}\t{}\t{}",
+            attempt.answer, attempt.outcome, attempt.at
+        )?;
+        self.attempts.push(attempt);
+        Ok(())
+    }
+
+    /// Whether it's worth sending `answer` off, given what we already know.
+    pub fn check(
+        &self,
+        registry: &Registry,
+        part: PartId,
+        answer: &str,
+        now: u64,
+    ) -> Result<(), Refusal> {
+        if let Some(answer) = registry.get(part) {
+            return Err(Refusal::AlreadySolved {
+                answer: answer.to_string(),
+            });
+        }
+
+        let attempts = self.attempts.iter().filter(|attempt| attempt.part == part);
+        if let Some(limit) = self
+            .attempts
+            .iter()
+            .filter_map(|attempt| match attempt.outcome {
+                Outcome::RateLimited { wait } => Some(attempt.at + wait.as_secs()),
+                _ => None,
+            })
+            .max()
+            .filter(|limit| *limit > now)
+        {
+            return Err(Refusal::RateLimited {
+                remaining: Duration::from_secs(limit - now),
+            });
+        }
+
+        if let Some(attempt) = attempts
+            .clone()
+            .find(|attempt| attempt.answer == answer && attempt.outcome.is_wrong())
+        {
+            return Err(Refusal::AlreadyTried {
+                outcome: attempt.outcome.clone(),
+            });
+        }
+
+        let Ok(answer) = answer.parse::<i128>() else {
+            return Ok(());
+        };
+        let numbers = |outcome: Outcome| {
+            attempts
+                .clone()
+                .filter(move |attempt| attempt.outcome == outcome)
+                .filter_map(|attempt| attempt.answer.parse::<i128>().ok())
+        };
+        if let Some(bound) = numbers(Outcome::TooHigh)
+            .min()
+            .filter(|high| answer >= *high)
+        {
+            return Err(Refusal::TooHigh { bound });
+        }
+        if let Some(bound) = numbers(Outcome::TooLow).max().filter(|low| answer <= *low) {
+            return Err(Refusal::TooLow { bound });
+        }
+        Ok(())
+    }
+}
+
+fn parse_attempt(line: &str) -> Option<Attempt> {
+    let mut fields = line.split('\t');
+    let part = (
+        fields.next()?.parse().ok()?,
+        fields.next()?.parse().ok()?,
+        fields.next()?.parse().ok()?,
+    );
+    let answer = fields.next()?.to_string();
+    let outcome = Outcome::parse(fields.next()?)?;
+    let at = fields.next()?.parse().ok()?;
+    Some(Attempt {
+        part,
+        answer,
+        outcome,
+        at,
+    })
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("we're past 1970")
+        .as_secs()
+}
+
+/// Where the answer registry and the history of what we've submitted live.
+pub struct AnswerFiles {
+    pub registry: PathBuf,
+    pub history: PathBuf,
+}
+
+/// Talks to the site, or to anything that answers like it.
+pub struct Client {
+    /// e.g. `https://adventofcode.com`.
+    pub server: String,
+    /// The `session` cookie of a logged in browser.
+    pub session: String,
+}
+
+impl Client {
+    pub fn post_answer(&self, (year, day, part): PartId, answer: &str) -> Result<Outcome, String> {
+        let url = format!(
+            "{}/{year}/day/{day}/answer",
+            self.server.trim_end_matches('/')
+        );
+        let response = ureq::post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", "github.com/jasikpark/aoc runner")
+            .send_form(&[("level", &part.to_string()), ("answer", answer)])
+            .map_err(|error| error.to_string())?;
+        let html = response.into_string().map_err(|error| error.to_string())?;
+        Ok(parse_response(&html))
+    }
+
+    /// Submits the answer unless we know better, and records how it went.
+    pub fn submit(
+        &self,
+        files: &AnswerFiles,
+        part: PartId,
+        answer: &str,
+    ) -> Result<Outcome, String> {
+        let mut registry = Registry::load(&files.registry).map_err(|error| error.to_string())?;
+        let mut history = History::load(&files.history).map_err(|error| error.to_string())?;
+        history
+            .check(&registry, part, answer, now())
+            .map_err(|refusal| refusal.to_string())?;
+
+        let outcome = self.post_answer(part, answer)?;
+        history
+            .record(
+                &files.history,
+                Attempt {
+                    part,
+                    answer: answer.to_string(),
+                    outcome: outcome.clone(),
+                    at: now(),
+                },
+            )
+            .map_err(|error| error.to_string())?;
+        if outcome == Outcome::Correct {
+            registry.insert(part, answer.to_string());
+            registry
+                .save(&files.registry)
+                .map_err(|error| error.to_string())?;
+        }
+        Ok(outcome)
+    }
+}
+
+mod test {
+    #[allow(dead_code)]
+    fn attempt(answer: &str, outcome: crate::submit::Outcome, at: u64) -> crate::submit::Attempt {
+        crate::submit::Attempt {
+            part: (2023, 4, 2),
+            answer: answer.into(),
+            outcome,
+            at,
+        }
+    }
+
+    #[test]
+    fn parses_responses() {
+        use crate::submit::{parse_response, Outcome};
+        use std::time::Duration;
+
+        let page = |text: &str| format!("<main>\n<article><p>{text}</p></article>\n</main>");
+        assert_eq!(
+            parse_response(&page("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.")),
+            Outcome::Correct
+        );
+        assert_eq!(
+            parse_response(&page("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data; please wait one minute before trying again.")),
+            Outcome::TooHigh
+        );
+        assert_eq!(
+            parse_response(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Outcome::TooLow
+        );
+        assert_eq!(
+            parse_response(&page("That's not the right answer.  If you're stuck, make sure you're using the full input data.")),
+            Outcome::Wrong
+        );
+        assert_eq!(
+            parse_response(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 4m 33s left to wait. <a href=\"/2023/day/4\">[Return to Day 4]</a>")),
+            Outcome::RateLimited { wait: Duration::from_secs(273) }
+        );
+        assert_eq!(
+            parse_response(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            Outcome::WrongLevel
+        );
+        assert_eq!(
+            parse_response(&page("Puzzle inputs differ by user.")),
+            Outcome::Unknown("Puzzle inputs differ by user.".into())
+        );
+    }
+
+    #[test]
+    fn refuses_what_we_already_know() {
+        use crate::registry::Registry;
+        use crate::submit::{History, Outcome, Refusal};
+        use std::time::Duration;
+
+        let history = History {
+            attempts: vec![
+                attempt("5000", Outcome::TooLow, 0),
+                attempt("20000000", Outcome::TooHigh, 100),
+                attempt("14427617", Outcome::Wrong, 200),
+            ],
+        };
+        let registry = Registry::default();
+        let check = |answer| history.check(&registry, (2023, 4, 2), answer, 1000);
+
+        assert_eq!(check("14427616"), Ok(()));
+        assert_eq!(
+            check("14427617"),
+            Err(Refusal::AlreadyTried {
+                outcome: Outcome::Wrong
+            })
+        );
+        assert_eq!(check("20000001"), Err(Refusal::TooHigh { bound: 20000000 }));
+        assert_eq!(check("4999"), Err(Refusal::TooLow { bound: 5000 }));
+        assert_eq!(history.check(&registry, (2023, 4, 1), "4999", 1000), Ok(()));
+
+        let mut registry = Registry::default();
+        registry.insert((2023, 4, 2), "14427616".into());
+        assert_eq!(
+            history.check(&registry, (2023, 4, 2), "14427616", 1000),
+            Err(Refusal::AlreadySolved {
+                answer: "14427616".into()
+            })
+        );
+
+        let history = History {
+            attempts: vec![attempt(
+                "1",
+                Outcome::RateLimited {
+                    wait: Duration::from_secs(60),
+                },
+                1000,
+            )],
+        };
+        assert_eq!(
+            history.check(&Registry::default(), (2023, 5, 1), "2", 1030),
+            Err(Refusal::RateLimited {
+                remaining: Duration::from_secs(30)
+            })
+        );
+        assert_eq!(
+            history.check(&Registry::default(), (2023, 5, 1), "2", 1060),
+            Ok(())
+        );
+    }
+
+    /// A stand-in for the site that takes one request per response given, answering with the page
+    /// for each response, and hands back the requests it got.
+    #[allow(dead_code)]
+    fn stand_in_server(
+        responses: Vec<&'static str>,
+    ) -> (String, std::thread::JoinHandle<Vec<String>>) {
+        use std::io::{BufRead, BufReader, Read, Write};
+        use std::net::TcpListener;
+
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let server = format!("http://{}", listener.local_addr().unwrap());
+        let handle = std::thread::spawn(move || {
+            responses
+                .into_iter()
+                .map(|text| {
+                    let (stream, _) = listener.accept().unwrap();
+                    let mut reader = BufReader::new(stream);
+                    let mut request = String::new();
+                    let mut content_length = 0;
+                    loop {
+                        let mut line = String::new();
+                        reader.read_line(&mut line).unwrap();
+                        if let Some((name, value)) = line.split_once(':') {
+                            if name.eq_ignore_ascii_case("content-length") {
+                                content_length = value.trim().parse().unwrap();
+                            }
+                        }
+                        request.push_str(&line);
+                        if line == "\r\n" {
+                            break;
+                        }
+                    }
+                    let mut body = vec![0; content_length];
+                    reader.read_exact(&mut body).unwrap();
+                    request.push_str(&String::from_utf8(body).unwrap());
+
+                    let page = format!("<html><body><main><article><p>{text}</p></article></main></body></html>");
+                    write!(
+                        reader.get_mut(),
+                        "HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{page}",
+                        page.len()
+                    )
+                    .unwrap();
+                    request
+                })
+                .collect()
+        });
+        (server, handle)
+    }
+
+    #[test]
+    fn submits_to_stand_in_server() {
+        use crate::registry::Registry;
+        use crate::submit::{AnswerFiles, Client, History, Outcome};
+
+        let dir = std::env::temp_dir().join(format!("runner-submit-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let files = AnswerFiles {
+            registry: dir.join("registry.txt"),
+            history: dir.join("history.txt"),
+        };
+
+        let (server, requests) = stand_in_server(vec![
+            "That's not the right answer; your answer is too low.",
+            "That's the right answer!",
+        ]);
+        let client = Client {
+            server,
+            session: "53616c7465645f5f".into(),
+        };
+
+        assert_eq!(
+            client.submit(&files, (2023, 4, 2), "30"),
+            Ok(Outcome::TooLow)
+        );
+        assert_eq!(
+            client.submit(&files, (2023, 4, 2), "29"),
+            Err("30 was already too low".into())
+        );
+        assert_eq!(
+            client.submit(&files, (2023, 4, 2), "14427616"),
+            Ok(Outcome::Correct)
+        );
+        assert_eq!(
+            client.submit(&files, (2023, 4, 2), "14427616"),
+            Err("already solved, the answer is 14427616".into())
+        );
+
+        let requests = requests.join().unwrap();
+        let registry = Registry::load(&files.registry).unwrap();
+        let history = History::load(&files.history).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert!(requests[0].starts_with("POST /2023/day/4/answer HTTP/1.1\r\n"));
+        assert!(requests[0].contains("session=53616c7465645f5f"));
+        assert!(requests[0].ends_with("level=2&answer=30"));
+        assert!(requests[1].ends_with("level=2&answer=14427616"));
+        assert_eq!(registry.get((2023, 4, 2)), Some("14427616"));
+        assert_eq!(
+            history
+                .attempts
+                .iter()
+                .map(|attempt| (attempt.answer.as_str(), attempt.outcome.clone()))
+                .collect::<Vec<_>>(),
+            vec![("30", Outcome::TooLow), ("14427616", Outcome::Correct)]
+        );
+    }
+}
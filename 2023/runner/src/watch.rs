@@ -0,0 +1,152 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+/// When each file we're watching was last modified.
+type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+/// Polls a set of files and directories for changes, since all we need is to notice a save.
+pub struct Watcher {
+    roots: Vec<PathBuf>,
+    snapshot: Snapshot,
+    /// How long things have to stay quiet before we report a change, so an editor saving a
+    /// handful of files at once (or us mashing save) only triggers one run.
+    debounce: Duration,
+    poll: Duration,
+}
+
+impl Watcher {
+    pub fn new(roots: Vec<PathBuf>, debounce: Duration) -> Self {
+        Self {
+            snapshot: snapshot(&roots),
+            roots,
+            debounce,
+            poll: Duration::from_millis(100),
+        }
+    }
+
+    /// Blocks until something changes and then settles down, returning every file that changed.
+    pub fn wait_for_change(&mut self) -> Vec<PathBuf> {
+        let mut changed = vec![];
+        loop {
+            thread::sleep(if changed.is_empty() {
+                self.poll
+            } else {
+                self.debounce
+            });
+
+            let snapshot = snapshot(&self.roots);
+            let newly_changed = diff(&self.snapshot, &snapshot);
+            self.snapshot = snapshot;
+
+            if newly_changed.is_empty() && !changed.is_empty() {
+                changed.sort();
+                changed.dedup();
+                return changed;
+            }
+            changed.extend(newly_changed);
+        }
+    }
+}
+
+/// Finds every file under the roots, skipping build output and hidden files.
+fn snapshot(roots: &[PathBuf]) -> Snapshot {
+    fn visit(path: &Path, snapshot: &mut Snapshot) {
+        let is_ignored = path
+            .file_name()
+            .is_some_and(|name| name == "target" || name.to_string_lossy().starts_with('.'));
+        if is_ignored {
+            return;
+        }
+
+        if path.is_dir() {
+            for entry in fs::read_dir(path).into_iter().flatten().flatten() {
+                visit(&entry.path(), snapshot);
+            }
+        } else if let Ok(modified) = fs::metadata(path).and_then(|metadata| metadata.modified()) {
+            snapshot.insert(path.to_path_buf(), modified);
+        }
+    }
+
+    let mut snapshot = Snapshot::new();
+    for root in roots {
+        visit(root, &mut snapshot);
+    }
+    snapshot
+}
+
+/// Files that were added, modified or removed between the snapshots.
+fn diff(old: &Snapshot, new: &Snapshot) -> Vec<PathBuf> {
+    let modified = new
+        .iter()
+        .filter(|(path, modified)| old.get(*path) != Some(modified))
+        .map(|(path, _)| path.clone());
+    let removed = old.keys().filter(|path| !new.contains_key(*path)).cloned();
+    modified.chain(removed).collect()
+}
+
+/// Describes how the answer moved since the last run, e.g. `30 (was 13, +17)`.
+pub fn answer_diff(last: Option<&str>, current: &str) -> String {
+    let Some(last) = last else {
+        return current.to_string();
+    };
+    if last == current {
+        return format!("{current} (unchanged)");
+    }
+    match (last.parse::<i128>(), current.parse::<i128>()) {
+        (Ok(last_num), Ok(current_num)) => {
+            format!("{current} (was {last}, {:+})", current_num - last_num)
+        }
+        _ => format!("{current} (was {last})"),
+    }
+}
+
+mod test {
+    #[test]
+    fn answer_diffs() {
+        use crate::watch::answer_diff;
+
+        assert_eq!(answer_diff(None, "13"), "13");
+        assert_eq!(answer_diff(Some("13"), "13"), "13 (unchanged)");
+        assert_eq!(answer_diff(Some("13"), "30"), "30 (was 13, +17)");
+        assert_eq!(answer_diff(Some("30"), "13"), "13 (was 30, -17)");
+        assert_eq!(answer_diff(Some("ABC"), "ABD"), "ABD (was ABC)");
+    }
+
+    #[test]
+    fn rapid_saves_are_reported_once() {
+        use crate::watch::Watcher;
+        use std::{fs, thread, time::Duration};
+
+        let dir = std::env::temp_dir().join(format!("runner-watch-{}", std::process::id()));
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(dir.join("src/part_two.rs"), "todo!()").unwrap();
+        fs::write(dir.join("src/.part_two.rs.swp"), "").unwrap();
+
+        let mut watcher = Watcher::new(vec![dir.clone()], Duration::from_millis(300));
+        let writer = {
+            let dir = dir.clone();
+            thread::spawn(move || {
+                for attempt in 0..3 {
+                    thread::sleep(Duration::from_millis(50));
+                    fs::write(dir.join("src/part_two.rs"), format!("{attempt}")).unwrap();
+                    fs::write(dir.join("src/.part_two.rs.swp"), format!("{attempt}")).unwrap();
+                }
+                fs::write(dir.join("src/input.txt"), "Card 1: 1 | 1").unwrap();
+            })
+        };
+
+        let changed = watcher.wait_for_change();
+        writer.join().unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(
+            changed,
+            vec![dir.join("src/input.txt"), dir.join("src/part_two.rs")]
+        );
+    }
+}
@@ -0,0 +1,199 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Deserializer};
+
+pub mod report;
+pub mod table;
+
+/// A private leaderboard, as exported from its `.json` link.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Leaderboard {
+    pub event: u16,
+    pub members: Vec<Member>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Member {
+    pub id: u64,
+    pub name: String,
+    pub local_score: u64,
+    pub stars: u32,
+    /// Seconds since the unix epoch, 0 if they haven't got any stars.
+    pub last_star: u64,
+    pub days: BTreeMap<u8, Stars>,
+}
+
+/// When a member got the stars for a day, in seconds since the unix epoch.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Stars {
+    pub part_one: u64,
+    pub part_two: Option<u64>,
+}
+
+impl Leaderboard {
+    pub fn parse(json: &str) -> serde_json::Result<Self> {
+        let export: Export = serde_json::from_str(json)?;
+        let members = export
+            .members
+            .into_values()
+            .map(|member| Member {
+                name: member
+                    .name
+                    .unwrap_or_else(|| format!("(anonymous user #{})", member.id)),
+                id: member.id,
+                local_score: member.local_score,
+                stars: member.stars,
+                last_star: member.last_star_ts,
+                days: member
+                    .completion_day_level
+                    .into_iter()
+                    .filter_map(|(day, parts)| {
+                        Some((
+                            day,
+                            Stars {
+                                part_one: parts.get(&1)?.get_star_ts,
+                                part_two: parts.get(&2).map(|star| star.get_star_ts),
+                            },
+                        ))
+                    })
+                    .collect(),
+            })
+            .collect();
+        Ok(Self {
+            event: export.event,
+            members,
+        })
+    }
+
+    /// The last day anyone got a star on.
+    pub fn latest_day(&self) -> Option<u8> {
+        self.members
+            .iter()
+            .filter_map(|member| member.days.keys().next_back().copied())
+            .max()
+    }
+}
+
+impl Member {
+    /// Whether they got both stars for the day.
+    pub fn finished(&self, day: u8) -> bool {
+        self.days
+            .get(&day)
+            .is_some_and(|stars| stars.part_two.is_some())
+    }
+}
+
+/// When a day's puzzle unlocks, midnight in US Eastern, in seconds since the unix epoch.
+///
+/// ```
+/// assert_eq!(leaderboard::unlock(2023, 1), 1701406800);
+/// ```
+pub fn unlock(year: u16, day: u8) -> u64 {
+    // Days since 1970-01-01, from Howard Hinnant's `days_from_civil`, with December fixed as the month.
+    let year = u64::from(year);
+    let era = year / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * 9 + 2) / 5 + u64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    let days = era * 146097 + day_of_era - 719468;
+    days * 24 * 60 * 60 + 5 * 60 * 60
+}
+
+/// The leaderboard JSON, as the site writes it.
+#[derive(Deserialize)]
+struct Export {
+    #[serde(deserialize_with = "number_or_string")]
+    event: u16,
+    members: BTreeMap<String, ExportMember>,
+}
+
+#[derive(Deserialize)]
+struct ExportMember {
+    id: u64,
+    name: Option<String>,
+    local_score: u64,
+    stars: u32,
+    #[serde(deserialize_with = "number_or_string")]
+    last_star_ts: u64,
+    completion_day_level: BTreeMap<u8, BTreeMap<u8, ExportStar>>,
+}
+
+#[derive(Deserialize)]
+struct ExportStar {
+    #[serde(deserialize_with = "number_or_string")]
+    get_star_ts: u64,
+}
+
+/// Older exports quote their numbers, newer ones don't.
+fn number_or_string<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: std::str::FromStr + TryFrom<u64>,
+    T::Err: std::fmt::Display,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum NumberOrString {
+        Number(u64),
+        String(String),
+    }
+
+    match NumberOrString::deserialize(deserializer)? {
+        NumberOrString::Number(number) => T::try_from(number)
+            .map_err(|_| serde::de::Error::custom(format!("{number} is out of range"))),
+        NumberOrString::String(string) => string.parse().map_err(serde::de::Error::custom),
+    }
+}
+
+mod test {
+
+    #[test]
+    fn parses_export() {
+        use crate::{Leaderboard, Stars};
+
+        let leaderboard = Leaderboard::parse(include_str!("../examples/leaderboard.json")).unwrap();
+        assert_eq!(leaderboard.event, 2023);
+        assert_eq!(leaderboard.latest_day(), Some(4));
+
+        let names = leaderboard
+            .members
+            .iter()
+            .map(|member| member.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["jasikpark", "Ferris", "(anonymous user #3)"]);
+
+        let me = &leaderboard.members[0];
+        assert_eq!(
+            me.days[&3],
+            Stars {
+                part_one: 1701669600,
+                part_two: None
+            }
+        );
+        assert!(me.finished(2));
+        assert!(!me.finished(3));
+    }
+
+    #[test]
+    fn parses_quoted_numbers() {
+        use crate::Leaderboard;
+
+        let json = r#"{"event": "2020", "owner_id": "7", "members": {"7": {
+            "id": 7, "name": "old", "stars": 1, "local_score": 1, "global_score": 0,
+            "last_star_ts": "1606800000",
+            "completion_day_level": {"1": {"1": {"get_star_ts": "1606800000"}}}
+        }}}"#;
+        let leaderboard = Leaderboard::parse(json).unwrap();
+        assert_eq!(leaderboard.members[0].days[&1].part_one, 1606800000);
+    }
+
+    #[test]
+    fn unlocks_at_midnight_eastern() {
+        use crate::unlock;
+
+        // 2020-12-01T05:00:00Z
+        assert_eq!(unlock(2020, 1), 1606798800);
+        // 2024-12-25T05:00:00Z, through a leap year.
+        assert_eq!(unlock(2024, 25), 1735102800);
+    }
+}
@@ -0,0 +1,67 @@
+use std::path::PathBuf;
+
+use clap::{Args, Parser, Subcommand};
+use leaderboard::{report, table::Table, Leaderboard};
+
+#[derive(Parser)]
+#[command(about = "Reports on our private leaderboard's JSON export")]
+struct Cli {
+    #[command(subcommand)]
+    report: Report,
+}
+
+#[derive(Args)]
+struct Export {
+    /// The leaderboard JSON, saved from the leaderboard's `[API]` link.
+    path: PathBuf,
+    /// Write CSV instead of a table.
+    #[arg(long)]
+    csv: bool,
+}
+
+#[derive(Subcommand)]
+enum Report {
+    /// Everyone in leaderboard order.
+    Ranking {
+        #[command(flatten)]
+        export: Export,
+    },
+    /// How long everyone took to get each day's stars.
+    Times {
+        #[command(flatten)]
+        export: Export,
+        /// Just this day.
+        #[arg(long)]
+        day: Option<u8>,
+    },
+    /// Runs of consecutive days everyone finished.
+    Streaks {
+        #[command(flatten)]
+        export: Export,
+    },
+}
+
+impl Export {
+    fn read(&self) -> Leaderboard {
+        let json = std::fs::read_to_string(&self.path)
+            .unwrap_or_else(|error| panic!("couldn't read {}: {error}", self.path.display()));
+        Leaderboard::parse(&json)
+            .unwrap_or_else(|error| panic!("couldn't parse {}: {error}", self.path.display()))
+    }
+
+    fn print(&self, table: Table) {
+        if self.csv {
+            print!("{}", table.to_csv());
+        } else {
+            print!("{table}");
+        }
+    }
+}
+
+fn main() {
+    match Cli::parse().report {
+        Report::Ranking { export } => export.print(report::ranking(&export.read())),
+        Report::Times { export, day } => export.print(report::solve_times(&export.read(), day)),
+        Report::Streaks { export } => export.print(report::streaks(&export.read())),
+    }
+}
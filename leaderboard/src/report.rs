@@ -0,0 +1,124 @@
+use crate::{
+    table::{Cell, Table},
+    unlock, Leaderboard, Member,
+};
+
+/// Everyone in leaderboard order: by local score, ties going to whoever got there first.
+pub fn ranking(leaderboard: &Leaderboard) -> Table {
+    let mut members = leaderboard.members.iter().collect::<Vec<_>>();
+    members.sort_by_key(|member| (std::cmp::Reverse(member.local_score), member.last_star));
+
+    let mut table = Table::new(&["rank", "member", "score", "stars"]);
+    for (rank, member) in members.into_iter().enumerate() {
+        table.push(vec![
+            Cell::Count(rank as u64 + 1),
+            Cell::Text(member.name.clone()),
+            Cell::Count(member.local_score),
+            Cell::Count(member.stars.into()),
+        ]);
+    }
+    table
+}
+
+/// How long after the puzzle unlocked everyone got their stars, and how long part two took on top.
+///
+/// Only the given day if there is one.
+pub fn solve_times(leaderboard: &Leaderboard, day: Option<u8>) -> Table {
+    let mut table = Table::new(&["day", "member", "part one", "part two", "gap"]);
+    let days = day.map_or(1..=leaderboard.latest_day().unwrap_or(0), |day| day..=day);
+    for day in days {
+        let unlocked = unlock(leaderboard.event, day);
+        let mut solved = leaderboard
+            .members
+            .iter()
+            .filter_map(|member| Some((member, *member.days.get(&day)?)))
+            .collect::<Vec<_>>();
+        solved.sort_by_key(|(_, stars)| (stars.part_two.is_none(), stars.part_two, stars.part_one));
+
+        for (member, stars) in solved {
+            table.push(vec![
+                Cell::Count(day.into()),
+                Cell::Text(member.name.clone()),
+                Cell::Duration(Some(stars.part_one.saturating_sub(unlocked))),
+                Cell::Duration(stars.part_two.map(|at| at.saturating_sub(unlocked))),
+                Cell::Duration(stars.part_two.map(|at| at.saturating_sub(stars.part_one))),
+            ]);
+        }
+    }
+    table
+}
+
+/// Runs of consecutive days each member got both stars on.
+///
+/// The current streak is the one still going on the latest day anyone has a star for.
+pub fn streaks(leaderboard: &Leaderboard) -> Table {
+    let latest = leaderboard.latest_day().unwrap_or(0);
+    let mut members = leaderboard
+        .members
+        .iter()
+        .map(|member| (member, streak(member, latest)))
+        .collect::<Vec<_>>();
+    members.sort_by_key(|(_, (longest, current))| std::cmp::Reverse((*current, *longest)));
+
+    let mut table = Table::new(&["member", "finished", "longest", "current"]);
+    for (member, (longest, current)) in members {
+        table.push(vec![
+            Cell::Text(member.name.clone()),
+            Cell::Count((1..=latest).filter(|day| member.finished(*day)).count() as u64),
+            Cell::Count(longest),
+            Cell::Count(current),
+        ]);
+    }
+    table
+}
+
+/// The member's longest and current streaks, up to and including the `latest` day.
+fn streak(member: &Member, latest: u8) -> (u64, u64) {
+    (1..=latest).fold((0, 0), |(longest, current), day| {
+        let current = if member.finished(day) { current + 1 } else { 0 };
+        (longest.max(current), current)
+    })
+}
+
+mod test {
+
+    #[test]
+    fn reports() {
+        use crate::{report, Leaderboard};
+
+        let leaderboard = Leaderboard::parse(include_str!("../examples/leaderboard.json")).unwrap();
+
+        insta::assert_snapshot!(report::ranking(&leaderboard).to_string(), @"
+        rank │ member              │ score │ stars
+        ─────┼─────────────────────┼───────┼──────
+        1    │ Ferris              │ 21    │ 7
+        2    │ jasikpark           │ 15    │ 7
+        3    │ (anonymous user #3) │ 1     │ 1
+        ");
+        insta::assert_snapshot!(report::solve_times(&leaderboard, None).to_string(), @"
+        day │ member              │ part one │ part two │ gap
+        ────┼─────────────────────┼──────────┼──────────┼────────
+        1   │ Ferris              │ 0:05:00  │ 0:06:40  │ 0:01:40
+        1   │ jasikpark           │ 0:10:00  │ 0:25:00  │ 0:15:00
+        1   │ (anonymous user #3) │ 2:00:00  │ -        │ -
+        2   │ Ferris              │ 0:20:00  │ 0:33:20  │ 0:13:20
+        2   │ jasikpark           │ 1:00:00  │ 1:05:00  │ 0:05:00
+        3   │ Ferris              │ 0:33:20  │ 1:23:20  │ 0:50:00
+        3   │ jasikpark           │ 25:00:00 │ -        │ -
+        4   │ jasikpark           │ 0:05:00  │ 0:07:00  │ 0:02:00
+        4   │ Ferris              │ 0:03:20  │ -        │ -
+        ");
+        insta::assert_snapshot!(report::solve_times(&leaderboard, Some(3)).to_csv(), @"
+        day,member,part one,part two,gap
+        3,Ferris,2000,5000,3000
+        3,jasikpark,90000,,
+        ");
+        insta::assert_snapshot!(report::streaks(&leaderboard).to_string(), @"
+        member              │ finished │ longest │ current
+        ────────────────────┼──────────┼─────────┼────────
+        jasikpark           │ 3        │ 2       │ 1
+        Ferris              │ 3        │ 3       │ 0
+        (anonymous user #3) │ 0        │ 0       │ 0
+        ");
+    }
+}
@@ -0,0 +1,156 @@
+use std::fmt;
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Cell {
+    Text(String),
+    Count(u64),
+    /// In seconds, `None` when there's nothing to time.
+    Duration(Option<u64>),
+}
+
+impl Cell {
+    /// How the cell reads in the terminal, durations as `h:mm:ss`.
+    fn display(&self) -> String {
+        match self {
+            Cell::Text(text) => text.clone(),
+            Cell::Count(count) => count.to_string(),
+            Cell::Duration(None) => "-".into(),
+            Cell::Duration(Some(seconds)) => format!(
+                "{}:{:02}:{:02}",
+                seconds / 3600,
+                seconds / 60 % 60,
+                seconds % 60
+            ),
+        }
+    }
+
+    /// How the cell reads in a spreadsheet, durations as plain seconds.
+    fn csv(&self) -> String {
+        match self {
+            Cell::Text(text) if text.contains([',', '"', '\n']) => {
+                format!("\"{}\"", text.replace('"', "\"\""))
+            }
+            Cell::Text(text) => text.clone(),
+            Cell::Count(count) => count.to_string(),
+            Cell::Duration(seconds) => seconds
+                .map(|seconds| seconds.to_string())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// A report, ready to print as a table or write out as CSV.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Table {
+    pub columns: Vec<&'static str>,
+    pub rows: Vec<Vec<Cell>>,
+}
+
+impl Table {
+    pub fn new(columns: &[&'static str]) -> Self {
+        Self {
+            columns: columns.to_vec(),
+            rows: vec![],
+        }
+    }
+
+    pub fn push(&mut self, row: Vec<Cell>) {
+        assert_eq!(
+            row.len(),
+            self.columns.len(),
+            "a row needs a cell for every column"
+        );
+        self.rows.push(row);
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv = self.columns.join(",");
+        csv.push('\n');
+        for row in &self.rows {
+            let cells = row.iter().map(Cell::csv).collect::<Vec<_>>();
+            csv.push_str(&cells.join(","));
+            csv.push('\n');
+        }
+        csv
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows = self
+            .rows
+            .iter()
+            .map(|row| row.iter().map(Cell::display).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let widths = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(column, name)| {
+                rows.iter()
+                    .map(|row| row[column].chars().count())
+                    .chain(std::iter::once(name.chars().count()))
+                    .max()
+                    .unwrap_or_default()
+            })
+            .collect::<Vec<_>>();
+
+        let line = |cells: Vec<String>| {
+            cells
+                .iter()
+                .zip(&widths)
+                .map(|(cell, width)| format!("{cell:width$}"))
+                .collect::<Vec<_>>()
+                .join(" │ ")
+                .trim_end()
+                .to_string()
+        };
+        writeln!(
+            f,
+            "{}",
+            line(self.columns.iter().map(|name| name.to_string()).collect())
+        )?;
+        writeln!(
+            f,
+            "{}",
+            widths
+                .iter()
+                .map(|width| "─".repeat(*width))
+                .collect::<Vec<_>>()
+                .join("─┼─")
+        )?;
+        for row in rows {
+            writeln!(f, "{}", line(row))?;
+        }
+        Ok(())
+    }
+}
+
+mod test {
+
+    #[test]
+    fn renders_and_writes_csv() {
+        use crate::table::{Cell, Table};
+
+        let mut table = Table::new(&["member", "solve time"]);
+        table.push(vec![
+            Cell::Text("Ferris".into()),
+            Cell::Duration(Some(3725)),
+        ]);
+        table.push(vec![
+            Cell::Text("Rust, \"the\" crab".into()),
+            Cell::Duration(None),
+        ]);
+
+        insta::assert_snapshot!(table.to_string(), @r#"
+        member           │ solve time
+        ─────────────────┼───────────
+        Ferris           │ 1:02:05
+        Rust, "the" crab │ -
+        "#);
+        assert_eq!(
+            table.to_csv(),
+            "member,solve time\nFerris,3725\n\"Rust, \"\"the\"\" crab\",\n"
+        );
+    }
+}